@@ -0,0 +1,37 @@
+//! Compares the default `String` storage of paths with the `arc-str` feature:
+//!
+//! ```sh
+//! cargo bench --bench clone
+//! cargo bench --bench clone --features arc-str
+//! ```
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use x_path::{AbsoluteFilePath, AbsoluteFolderPath, Join, RelativeFilePath};
+
+fn clone(c: &mut Criterion) {
+    let file = AbsoluteFilePath::try_from("/home/user/projects/x-path/src/inner/path.rs").unwrap();
+    let short = AbsoluteFilePath::try_from("/a/b.rs").unwrap();
+
+    c.bench_function("clone", |b| b.iter(|| black_box(&file).clone()));
+    c.bench_function("clone short", |b| b.iter(|| black_box(&short).clone()));
+    c.bench_function("clone 100", |b| {
+        b.iter(|| vec![black_box(&file).clone(); 100])
+    });
+}
+
+fn builders(c: &mut Criterion) {
+    let file = AbsoluteFilePath::try_from("/home/user/projects/x-path/src/inner/path.rs").unwrap();
+    let folder = AbsoluteFolderPath::try_from("/home/user/projects/x-path/").unwrap();
+    let rel = RelativeFilePath::try_from("src/lib.rs").unwrap();
+
+    c.bench_function("with_extension", |b| {
+        b.iter(|| black_box(&file).with_extension("txt"))
+    });
+    c.bench_function("pushing", |b| {
+        b.iter(|| black_box(&folder).pushing("target").unwrap())
+    });
+    c.bench_function("popping", |b| b.iter(|| black_box(&folder).popping()));
+    c.bench_function("joining", |b| b.iter(|| black_box(&folder).joining(&rel)));
+}
+
+criterion_group!(benches, clone, builders);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+use std::fmt::{self, Display};
+
+use crate::AbsolutePath;
+
+/// Returned, wrapped in an [anyhow::Error], when the file system resolves a path
+/// to another kind than its type promises. For instance, when canonicalizing an
+/// [AbsoluteFilePath](crate::AbsoluteFilePath) that is a symlink to a folder.
+///
+/// ```
+/// # fn handle(err: anyhow::Error) {
+/// if let Some(changed) = err.downcast_ref::<x_path::KindChanged>() {
+///     println!("{} is now {}", changed.path, changed.resolved);
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct KindChanged {
+    /// The path as given
+    pub path: AbsolutePath,
+    /// What the file system resolved it to
+    pub resolved: AbsolutePath,
+}
+
+impl KindChanged {
+    fn kind(path: &AbsolutePath) -> &'static str {
+        if path.0.is_folder() {
+            "folder"
+        } else {
+            "file"
+        }
+    }
+}
+
+impl Display for KindChanged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the {} {} resolves to the {} {}",
+            Self::kind(&self.path),
+            self.path,
+            Self::kind(&self.resolved),
+            self.resolved
+        )
+    }
+}
+
+impl std::error::Error for KindChanged {}
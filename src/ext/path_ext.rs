@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::bail;
 
+#[cfg_attr(test, allow(dead_code))]
 pub(crate) trait PathBufExt {
     fn try_to_string(&self) -> anyhow::Result<String>;
 }
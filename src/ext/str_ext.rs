@@ -5,7 +5,6 @@ use super::CharExt;
 use anyhow::{bail, ensure, Result};
 
 pub(crate) trait PathStrExt {
-    fn is_absolute(&self) -> bool;
     fn assert_allowed_path_component(&self) -> Result<()>;
     fn assert_allowed_file_name(&self) -> Result<()>;
     fn after_last_slash_from(&self, pos: usize) -> usize;
@@ -24,10 +23,6 @@ impl PathStrExt for str {
             .unwrap_or_else(|| self.len())
     }
 
-    fn is_absolute(&self) -> bool {
-        self.starts_with(SLASH) || (self.len() >= 3 && [":/", ":\\"].contains(&&self[1..3]))
-    }
-
     fn assert_allowed_path_component(&self) -> Result<()> {
         ensure!(
             self.len() <= 255,
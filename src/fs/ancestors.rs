@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{inner::PathInner, os::CurrentOS, AbsoluteFilePath, AbsoluteFolderPath, AbsolutePath};
+
+/// Where a search upwards through the ancestor folders stops. By default it
+/// continues to the root.
+///
+/// ```
+/// use x_path::StopAt;
+///
+/// let stop = StopAt::default().home().file_system_boundary().max_depth(5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StopAt {
+    home: bool,
+    file_system_boundary: bool,
+    max_depth: Option<usize>,
+}
+
+impl StopAt {
+    /// Stops at the user's home folder, which is still searched, unless the search
+    /// starts outside of it.
+    pub fn home(mut self) -> Self {
+        self.home = true;
+        self
+    }
+
+    /// Doesn't continue into a parent folder on another file system, i.e. a mount point.
+    /// Only supported on unix.
+    pub fn file_system_boundary(mut self) -> Self {
+        self.file_system_boundary = true;
+        self
+    }
+
+    /// The number of levels above the start folder that are searched.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// The folders to search, nearest first.
+    fn folders(&self, start: &AbsoluteFolderPath) -> Vec<AbsoluteFolderPath> {
+        let mut ancestors = start.0.ancestor_folders();
+        if let Some(depth) = self.max_depth {
+            ancestors.truncate(depth + 1);
+        }
+        if self.home {
+            if let Ok(home) = PathInner::<CurrentOS>::new("~") {
+                if let Some(pos) = ancestors.iter().position(|a| *a == home) {
+                    ancestors.truncate(pos + 1);
+                }
+            }
+        }
+        if self.file_system_boundary {
+            if let Some(pos) = ancestors
+                .windows(2)
+                .position(|w| !same_device(w[0].as_path(), w[1].as_path()))
+            {
+                ancestors.truncate(pos + 1);
+            }
+        }
+        ancestors
+            .into_iter()
+            .map(AbsoluteFolderPath::from_inner)
+            .collect()
+    }
+}
+
+#[cfg(unix)]
+fn same_device(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    match (a.metadata(), b.metadata()) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev(),
+        _ => true,
+    }
+}
+
+#[cfg(not(unix))]
+fn same_device(_a: &Path, _b: &Path) -> bool {
+    true
+}
+
+/// Looks for `marker` in `folder`, where a marker that ends with a slash
+/// must be a folder and otherwise a file.
+fn find_marker(folder: &AbsoluteFolderPath, marker: &str) -> Option<AbsolutePath> {
+    let inner = PathInner::new_expanded(&format!("{}{marker}", folder.0.as_str())).ok()?;
+    let path = inner.as_path_without_slash();
+    let found = if inner.is_folder() {
+        path.is_dir()
+    } else {
+        path.is_file()
+    };
+    found.then_some(AbsolutePath::from_inner(inner))
+}
+
+impl AbsoluteFolderPath {
+    /// Finds the nearest folder, starting with this one and continuing upwards,
+    /// that contains any of the markers, ex: `Cargo.toml` or `.git/`. A marker that
+    /// ends with a slash has to be a folder. Returns the folder and the marker found.
+    pub fn find_ancestor_with(
+        &self,
+        markers: &[&str],
+    ) -> Option<(AbsoluteFolderPath, AbsolutePath)> {
+        self.find_ancestor_with_stop(markers, &StopAt::default())
+    }
+
+    /// Same as [find_ancestor_with](Self::find_ancestor_with) but stops the search
+    /// as given by `stop`.
+    pub fn find_ancestor_with_stop(
+        &self,
+        markers: &[&str],
+        stop: &StopAt,
+    ) -> Option<(AbsoluteFolderPath, AbsolutePath)> {
+        stop.folders(self).into_iter().find_map(|folder| {
+            let found = markers.iter().find_map(|m| find_marker(&folder, m))?;
+            Some((folder, found))
+        })
+    }
+}
+
+/// The files with any of the given names in a folder and its ancestors, for
+/// loading configuration in layers where nearer files override the ones further up.
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{AbsoluteFolderPath, Cascade, StopAt};
+///
+/// let cwd = AbsoluteFolderPath::try_from(".")?;
+/// let cascade = Cascade::find(&cwd, &["app.toml"], &StopAt::default().home())?;
+/// for file in cascade.root_first() {
+///     let layer = file.read_to_string()?;
+///     // apply the layer
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Cascade {
+    /// nearest first
+    files: Vec<AbsoluteFilePath>,
+}
+
+impl Cascade {
+    /// Collects the files in `start` and its ancestors. When a folder contains
+    /// several of the names they are collected in the order of `names`.
+    pub fn find(start: &AbsoluteFolderPath, names: &[&str], stop: &StopAt) -> Result<Self> {
+        let mut files = Vec::new();
+        for folder in stop.folders(start) {
+            for name in names {
+                let file = AbsoluteFilePath::from_inner(folder.0.child(name, false)?);
+                if file.exists() {
+                    files.push(file);
+                }
+            }
+        }
+        Ok(Self { files })
+    }
+
+    /// The files starting with the one nearest to the start folder.
+    pub fn nearest_first(&self) -> impl Iterator<Item = &AbsoluteFilePath> {
+        self.files.iter()
+    }
+
+    /// The files starting with the one furthest up, which is the order to
+    /// apply configuration layers.
+    pub fn root_first(&self) -> impl Iterator<Item = &AbsoluteFilePath> {
+        self.files.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+}
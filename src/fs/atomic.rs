@@ -0,0 +1,180 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+};
+
+use anyhow::{Context, Result};
+
+use crate::{AbsoluteFilePath, AbsoluteFolderPathRef, FileLike};
+
+use super::unique_name;
+
+impl AbsoluteFilePath {
+    /// Writes the contents to a temporary file next to this one and then renames
+    /// it over this file, so that readers either see the old or the new content
+    /// but never a partially written file. The permissions of an existing file
+    /// are kept.
+    pub fn write_atomic<C: AsRef<[u8]>>(&self, contents: C) -> Result<()> {
+        let mut writer = self.atomic_writer()?;
+        writer
+            .write_all(contents.as_ref())
+            .with_context(|| format!("could not write file: {self}"))?;
+        writer.commit()
+    }
+
+    /// Like [write_atomic](Self::write_atomic) but keeps the previous content
+    /// in a `<file name>.bak` file next to it, which is returned if there was
+    /// any previous content.
+    pub fn write_atomic_with_backup<C: AsRef<[u8]>>(
+        &self,
+        contents: C,
+    ) -> Result<Option<AbsoluteFilePath>> {
+        let mut writer = self.atomic_writer()?;
+        writer
+            .write_all(contents.as_ref())
+            .with_context(|| format!("could not write file: {self}"))?;
+        writer.commit_with_backup()
+    }
+
+    /// A writer for large content that is streamed to a temporary file which
+    /// replaces this file when [committed](AtomicWriter::commit).
+    pub fn atomic_writer(&self) -> Result<AtomicWriter> {
+        AtomicWriter::new(self)
+    }
+}
+
+/// Writes to a temporary file that replaces the target file on [commit](Self::commit).
+/// If dropped without committing, the temporary file is removed and the target
+/// is left untouched.
+pub struct AtomicWriter {
+    target: AbsoluteFilePath,
+    temp: AbsoluteFilePath,
+    file: Option<File>,
+}
+
+impl AtomicWriter {
+    fn new(target: &AbsoluteFilePath) -> Result<Self> {
+        let folder = target.dropping_file();
+        let (temp, file) = create_temp_file(folder, target.file_name())
+            .with_context(|| format!("could not create a temporary file for: {target}"))?;
+
+        let mut me = Self {
+            target: target.clone(),
+            temp,
+            file: Some(file),
+        };
+        me.copy_permissions()?;
+        Ok(me)
+    }
+
+    fn copy_permissions(&mut self) -> Result<()> {
+        if let Ok(meta) = fs::metadata(&self.target) {
+            if let Some(file) = &self.file {
+                file.set_permissions(meta.permissions())
+                    .with_context(|| format!("could not set permissions on: {}", self.temp))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The file that is replaced on commit
+    pub fn target(&self) -> &AbsoluteFilePath {
+        &self.target
+    }
+
+    /// Flushes and syncs the written content to disk and replaces the target with it.
+    pub fn commit(mut self) -> Result<()> {
+        self.sync()?;
+        self.replace_target()
+    }
+
+    /// Like [commit](Self::commit) but keeps the previous content of the target in a
+    /// `<file name>.bak` file next to it, which is returned if there was any previous content.
+    pub fn commit_with_backup(mut self) -> Result<Option<AbsoluteFilePath>> {
+        self.sync()?;
+        let backup = if self.target.exists() {
+            let backup = self
+                .target
+                .with_file_name(&format!("{}.bak", self.target.file_name()))?;
+            fs::copy(&self.target, &backup)
+                .with_context(|| format!("could not back up {} to {backup}", self.target))?;
+            Some(backup)
+        } else {
+            None
+        };
+        self.replace_target()?;
+        Ok(backup)
+    }
+
+    fn sync(&mut self) -> Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()
+                .and_then(|_| file.sync_all())
+                .with_context(|| format!("could not write file: {}", self.temp))?;
+        }
+        Ok(())
+    }
+
+    fn replace_target(&self) -> Result<()> {
+        fs::rename(&self.temp, &self.target)
+            .with_context(|| format!("could not replace {} with {}", self.target, self.temp))?;
+        sync_folder(self.target.dropping_file())
+    }
+}
+
+impl Write for AtomicWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.file {
+            Some(file) => file.write(buf),
+            None => Err(io::Error::other("the atomic writer is already committed")),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AtomicWriter {
+    fn drop(&mut self) {
+        // the temp file is gone after a successful commit
+        if self.temp.exists() {
+            self.file.take();
+            let _ = fs::remove_file(&self.temp);
+        }
+    }
+}
+
+fn create_temp_file(
+    folder: &AbsoluteFolderPathRef,
+    name: &str,
+) -> Result<(AbsoluteFilePath, File)> {
+    loop {
+        let temp = AbsoluteFilePath::from_inner(
+            folder
+                .inner
+                .child(&unique_name(&format!(".{name}."), "tmp"), false)?,
+        );
+        match OpenOptions::new().write(true).create_new(true).open(&temp) {
+            Ok(file) => return Ok((temp, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Makes the rename durable by syncing the folder it happened in.
+#[cfg(unix)]
+fn sync_folder(folder: &AbsoluteFolderPathRef) -> Result<()> {
+    File::open(folder)
+        .and_then(|f| f.sync_all())
+        .with_context(|| format!("could not sync folder: {folder}"))
+}
+
+#[cfg(not(unix))]
+fn sync_folder(_folder: &AbsoluteFolderPathRef) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::{fs, io};
+
+use anyhow::{ensure, Context, Result};
+
+use crate::{AbsoluteFilePath, AbsoluteFolderPath, FileLike};
+
+impl AbsoluteFilePath {
+    pub fn read(&self) -> Result<Vec<u8>> {
+        fs::read(self).with_context(|| format!("could not read file: {self}"))
+    }
+
+    pub fn read_to_string(&self) -> Result<String> {
+        fs::read_to_string(self).with_context(|| format!("could not read file: {self}"))
+    }
+
+    /// Writes the contents to the file, replacing it if it exists.
+    pub fn write<C: AsRef<[u8]>>(&self, contents: C) -> Result<()> {
+        fs::write(self, contents).with_context(|| format!("could not write file: {self}"))
+    }
+
+    pub fn remove(&self) -> Result<()> {
+        fs::remove_file(self).with_context(|| format!("could not remove file: {self}"))
+    }
+
+    /// Copies the file into `folder`, keeping the file name, and returns the copy.
+    /// Fails if the copy would be the file itself, ex: when `folder` is its own folder.
+    pub fn copy_to(&self, folder: &AbsoluteFolderPath) -> Result<AbsoluteFilePath> {
+        let to = AbsoluteFilePath::from_inner(folder.0.child(self.file_name(), false)?);
+        ensure!(
+            !to.exists() || !self.is_same_file(&to)?,
+            "cannot copy file {self} onto itself: {to}"
+        );
+        fs::copy(self, &to).with_context(|| format!("could not copy file {self} to {to}"))?;
+        Ok(to)
+    }
+
+    /// Moves the file into `folder`, keeping the file name, and returns the new path.
+    /// Falls back to copy and remove when the folder is on another file system.
+    pub fn move_to(&self, folder: &AbsoluteFolderPath) -> Result<AbsoluteFilePath> {
+        let to = AbsoluteFilePath::from_inner(folder.0.child(self.file_name(), false)?);
+        self.rename_or_copy(&to)?;
+        Ok(to)
+    }
+
+    /// Renames the file within its folder and returns the new path.
+    pub fn rename_to(&self, file_name: &str) -> Result<AbsoluteFilePath> {
+        let to = self.with_file_name(file_name)?;
+        fs::rename(self, &to).with_context(|| format!("could not rename file {self} to {to}"))?;
+        Ok(to)
+    }
+
+    fn rename_or_copy(&self, to: &AbsoluteFilePath) -> Result<()> {
+        match fs::rename(self, to) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+                fs::copy(self, to)
+                    .with_context(|| format!("could not move file {self} to {to}"))?;
+                self.remove()
+            }
+            Err(e) => Err(e).with_context(|| format!("could not move file {self} to {to}")),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs,
+    path::{Path, MAIN_SEPARATOR},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+
+use crate::{AbsoluteFilePath, AbsoluteFolderPath, AbsolutePath};
+
+impl AbsoluteFolderPath {
+    /// Creates the folder including any missing parent folders.
+    pub fn create_all(&self) -> Result<()> {
+        fs::create_dir_all(self).with_context(|| format!("could not create folder: {self}"))
+    }
+
+    /// Removes the folder and everything in it.
+    pub fn remove_all(&self) -> Result<()> {
+        fs::remove_dir_all(self).with_context(|| format!("could not remove folder: {self}"))
+    }
+
+    /// The files and folders directly in this folder, where the folders
+    /// end with a slash. Symlinks are followed to determine the kind.
+    pub fn read_dir(&self) -> Result<Vec<AbsolutePath>> {
+        let entries =
+            fs::read_dir(self).with_context(|| format!("could not read folder: {self}"))?;
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = entry.with_context(|| format!("could not read folder: {self}"))?;
+            let Ok(name) = entry.file_name().into_string() else {
+                bail!(
+                    "Non UTF-8 characters in path: {}",
+                    entry.path().to_string_lossy()
+                )
+            };
+            let is_dir = entry.path().is_dir();
+            paths.push(AbsolutePath::from_inner(self.0.child(&name, is_dir)?));
+        }
+        Ok(paths)
+    }
+
+    /// Copies the folder with everything in it into `folder`, keeping the
+    /// folder name, and returns the copy. Symlinks are copied as links, so a
+    /// link to a folder is not followed. Fails if `folder` is inside this folder,
+    /// or if the copy would be the folder itself, ex: when `folder` is its parent.
+    pub fn copy_tree_to(&self, folder: &AbsoluteFolderPath) -> Result<AbsoluteFolderPath> {
+        let Some(name) = self.0.segments().next_back() else {
+            bail!("cannot copy the root folder: {self}");
+        };
+        ensure!(
+            !folder.starts_with(self),
+            "cannot copy folder {self} into itself: {folder}"
+        );
+        let to = AbsoluteFolderPath::from_inner(folder.0.child(name, true)?);
+        ensure!(
+            to != *self && !(to.exists() && self.is_same_file(&to)?),
+            "cannot copy folder {self} onto itself: {to}"
+        );
+        to.create_all()?;
+        self.copy_content_to(&to)?;
+        Ok(to)
+    }
+
+    fn copy_content_to(&self, to: &AbsoluteFolderPath) -> Result<()> {
+        for path in self.read_dir()? {
+            let meta = fs::symlink_metadata(without_slash(&path))
+                .with_context(|| format!("could not read metadata for: {path}"))?;
+            if meta.is_symlink() {
+                copy_link(&path, to)?;
+            } else if path.0.is_folder() {
+                AbsoluteFolderPath::from_inner(path.0).copy_tree_to(to)?;
+            } else {
+                AbsoluteFilePath::from_inner(path.0).copy_to(to)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates a link in `to` with the same name and target as the link at `path`.
+fn copy_link(path: &AbsolutePath, to: &AbsoluteFolderPath) -> Result<()> {
+    let target = fs::read_link(without_slash(path))
+        .with_context(|| format!("could not read link: {path}"))?;
+    let name = path.segments().next_back().unwrap_or_default();
+    let link = to.as_path().join(name);
+    #[cfg(unix)]
+    let created = std::os::unix::fs::symlink(&target, &link);
+    #[cfg(windows)]
+    let created = if path.0.is_folder() {
+        std::os::windows::fs::symlink_dir(&target, &link)
+    } else {
+        std::os::windows::fs::symlink_file(&target, &link)
+    };
+    created.with_context(|| format!("could not copy link {path} to: {to}"))
+}
+
+/// The path of a folder without its trailing slash, which would make the OS
+/// follow a link to the folder.
+fn without_slash(path: &AbsolutePath) -> &Path {
+    Path::new(path.as_str().trim_end_matches(MAIN_SEPARATOR))
+}
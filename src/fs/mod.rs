@@ -0,0 +1,37 @@
+//! Typed file system operations on the absolute path types.
+
+mod ancestors;
+mod atomic;
+mod file;
+mod folder;
+mod temp;
+mod tree;
+
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub use ancestors::{Cascade, StopAt};
+pub use atomic::AtomicWriter;
+pub(crate) use temp::os_temp_folder;
+pub use temp::{TempFile, TempFolder};
+pub use tree::{assert_tree_eq, Tree, TreeDiff, TreeEntry};
+
+/// A file name that is unique within this process and very unlikely
+/// to collide with other processes: `<prefix><pid>-<nanos>-<counter>.<ext>`
+pub(crate) fn unique_name(prefix: &str, ext: &str) -> String {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let pid = std::process::id();
+    if ext.is_empty() {
+        format!("{prefix}{pid}-{nanos}-{count}")
+    } else {
+        format!("{prefix}{pid}-{nanos}-{count}.{ext}")
+    }
+}
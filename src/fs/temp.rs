@@ -0,0 +1,177 @@
+use std::{
+    fs::{self, OpenOptions},
+    io,
+    ops::Deref,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{ext::PathExt, inner::PathInner, AbsoluteFilePath, AbsoluteFolderPath};
+
+use super::unique_name;
+
+const PREFIX: &str = "x-path-";
+
+/// A uniquely named folder that is removed, with everything in it, when dropped.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let tmp = x_path::TempFolder::new()?;
+/// assert!(tmp.exists());
+/// # Ok(())
+/// # }
+/// ```
+pub struct TempFolder {
+    path: AbsoluteFolderPath,
+    keep: bool,
+}
+
+impl TempFolder {
+    /// Creates a folder in the OS temp folder.
+    pub fn new() -> Result<Self> {
+        Self::new_in(&os_temp_folder()?)
+    }
+
+    /// Creates a folder inside `parent`, which must exist.
+    pub fn new_in(parent: &AbsoluteFolderPath) -> Result<Self> {
+        loop {
+            let path =
+                AbsoluteFolderPath::from_inner(parent.0.child(&unique_name(PREFIX, ""), true)?);
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(Self { path, keep: false }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => {
+                    return Err(e).with_context(|| format!("could not create folder: {path}"))
+                }
+            }
+        }
+    }
+
+    /// Like [new](Self::new) but also returns the path, for code that passes the
+    /// path on and only keeps the guard alive, ex: `let (_guard, dir) = ...`.
+    pub fn new_pair() -> Result<(Self, AbsoluteFolderPath)> {
+        Self::new_pair_in(&os_temp_folder()?)
+    }
+
+    pub fn new_pair_in(parent: &AbsoluteFolderPath) -> Result<(Self, AbsoluteFolderPath)> {
+        let me = Self::new_in(parent)?;
+        let path = me.path.clone();
+        Ok((me, path))
+    }
+
+    pub fn path(&self) -> &AbsoluteFolderPath {
+        &self.path
+    }
+
+    /// Disables the removal, ex: to inspect the content when debugging.
+    pub fn keep(mut self) -> AbsoluteFolderPath {
+        self.keep = true;
+        self.path.clone()
+    }
+}
+
+impl Deref for TempFolder {
+    type Target = AbsoluteFolderPath;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl Drop for TempFolder {
+    fn drop(&mut self) {
+        if !self.keep {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+/// A uniquely named, initially empty, file that is removed when dropped.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::FileLike;
+///
+/// let tmp = x_path::TempFile::with_extension("json")?;
+/// tmp.write("{}")?;
+/// assert_eq!(tmp.extension(), Some("json"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct TempFile {
+    path: AbsoluteFilePath,
+    keep: bool,
+}
+
+impl TempFile {
+    /// Creates a file without extension in the OS temp folder.
+    pub fn new() -> Result<Self> {
+        Self::new_in(&os_temp_folder()?, "")
+    }
+
+    /// Creates a file with the extension, ex: `json`, in the OS temp folder.
+    pub fn with_extension(extension: &str) -> Result<Self> {
+        Self::new_in(&os_temp_folder()?, extension)
+    }
+
+    /// Creates a file inside `parent`, which must exist. An empty extension
+    /// means no extension.
+    pub fn new_in(parent: &AbsoluteFolderPath, extension: &str) -> Result<Self> {
+        loop {
+            let name = unique_name(PREFIX, extension);
+            let path = AbsoluteFilePath::from_inner(parent.0.child(&name, false)?);
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path, keep: false }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e).with_context(|| format!("could not create file: {path}")),
+            }
+        }
+    }
+
+    /// Like [with_extension](Self::with_extension) but also returns the path, for
+    /// code that passes the path on and only keeps the guard alive.
+    pub fn new_pair(extension: &str) -> Result<(Self, AbsoluteFilePath)> {
+        Self::new_pair_in(&os_temp_folder()?, extension)
+    }
+
+    pub fn new_pair_in(
+        parent: &AbsoluteFolderPath,
+        extension: &str,
+    ) -> Result<(Self, AbsoluteFilePath)> {
+        let me = Self::new_in(parent, extension)?;
+        let path = me.path.clone();
+        Ok((me, path))
+    }
+
+    pub fn path(&self) -> &AbsoluteFilePath {
+        &self.path
+    }
+
+    /// Disables the removal, ex: to inspect the content when debugging.
+    pub fn keep(mut self) -> AbsoluteFilePath {
+        self.keep = true;
+        self.path.clone()
+    }
+}
+
+impl Deref for TempFile {
+    type Target = AbsoluteFilePath;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if !self.keep {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+pub(crate) fn os_temp_folder() -> Result<AbsoluteFolderPath> {
+    let dir = std::env::temp_dir();
+    let inner = PathInner::new_expanded(dir.as_path().try_to_str()?)?;
+    AbsoluteFolderPath::from_fs(inner)
+}
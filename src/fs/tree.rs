@@ -0,0 +1,217 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use anyhow::{ensure, Result};
+
+use crate::{AbsoluteFilePath, AbsoluteFolderPath};
+
+/// A declarative description of files and folders, typically created with the
+/// [tree!](crate::tree!) macro, that can be created in a folder or compared
+/// with a folder's content.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{assert_tree_eq, tree, TempFolder};
+///
+/// let tmp = TempFolder::new()?;
+/// let spec = tree! {
+///     "src/" => { "main.rs" => "fn main() {}" },
+///     "README.md" => "",
+/// };
+/// spec.create_in(&tmp)?;
+/// assert_tree_eq(&tmp, &spec);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tree {
+    entries: BTreeMap<String, TreeEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeEntry {
+    File(String),
+    Folder(Tree),
+}
+
+/// A difference between an expected and an actual [Tree], where
+/// the path is relative to the compared folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeDiff {
+    Missing(String),
+    Unexpected(String),
+    Changed {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl Tree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file. Panics if the name isn't a valid file name.
+    pub fn file<C: Into<String>>(mut self, name: &str, content: C) -> Self {
+        let name = Self::entry_name(name, false);
+        self.entries.insert(name, TreeEntry::File(content.into()));
+        self
+    }
+
+    /// Adds a folder. The name may end with a slash.
+    /// Panics if the name isn't a valid folder name.
+    pub fn folder(mut self, name: &str, tree: Tree) -> Self {
+        let name = Self::entry_name(name, true);
+        self.entries.insert(name, TreeEntry::Folder(tree));
+        self
+    }
+
+    fn entry_name(name: &str, is_folder: bool) -> String {
+        let trimmed = if is_folder {
+            name.strip_suffix(['/', '\\']).unwrap_or(name)
+        } else {
+            name
+        };
+        if let Err(e) = Self::validate_name(trimmed) {
+            panic!("invalid tree entry '{name}': {e}");
+        }
+        trimmed.to_string()
+    }
+
+    fn validate_name(name: &str) -> Result<()> {
+        ensure!(!name.is_empty(), "An empty name is not valid");
+        ensure!(
+            !name.contains(['/', '\\']),
+            "a name cannot contain slashes, use a nested folder instead"
+        );
+        ensure!(
+            name != "." && name != "..",
+            "'{name}' is not allowed as a name"
+        );
+        Ok(())
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &TreeEntry)> {
+        self.entries
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry))
+    }
+
+    /// Creates all files and folders inside `folder`, which is created if missing.
+    /// Existing files are overwritten.
+    pub fn create_in(&self, folder: &AbsoluteFolderPath) -> Result<()> {
+        folder.create_all()?;
+        for (name, entry) in &self.entries {
+            match entry {
+                TreeEntry::File(content) => {
+                    AbsoluteFilePath::from_inner(folder.0.child(name, false)?).write(content)?
+                }
+                TreeEntry::Folder(tree) => {
+                    tree.create_in(&AbsoluteFolderPath::from_inner(folder.0.child(name, true)?))?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the content of `folder` into a tree. Files that aren't
+    /// UTF-8 are read lossily.
+    pub fn read(folder: &AbsoluteFolderPath) -> Result<Tree> {
+        let mut tree = Tree::new();
+        for path in folder.read_dir()? {
+            let Some(name) = path.0.segments().next_back().map(str::to_string) else {
+                continue;
+            };
+            let entry = if path.0.is_folder() {
+                TreeEntry::Folder(Tree::read(&AbsoluteFolderPath::from_inner(path.0))?)
+            } else {
+                let bytes = AbsoluteFilePath::from_inner(path.0).read()?;
+                TreeEntry::File(String::from_utf8_lossy(&bytes).into_owned())
+            };
+            tree.entries.insert(name, entry);
+        }
+        Ok(tree)
+    }
+
+    /// The differences when `self` is expected and `actual` is what was found.
+    pub fn diff(&self, actual: &Tree) -> Vec<TreeDiff> {
+        let mut diffs = Vec::new();
+        self.diff_into(actual, "", &mut diffs);
+        diffs
+    }
+
+    fn diff_into(&self, actual: &Tree, prefix: &str, diffs: &mut Vec<TreeDiff>) {
+        for (name, expected) in &self.entries {
+            let path = entry_path(prefix, name, expected);
+            match (expected, actual.entries.get(name)) {
+                (_, None) => diffs.push(TreeDiff::Missing(path)),
+                (TreeEntry::Folder(exp), Some(TreeEntry::Folder(act))) => {
+                    exp.diff_into(act, &path, diffs)
+                }
+                (TreeEntry::File(exp), Some(TreeEntry::File(act))) if exp != act => {
+                    diffs.push(TreeDiff::Changed {
+                        path,
+                        expected: exp.clone(),
+                        actual: act.clone(),
+                    })
+                }
+                (TreeEntry::File(_), Some(TreeEntry::File(_))) => {}
+                (_, Some(act)) => {
+                    diffs.push(TreeDiff::Missing(path));
+                    diffs.push(TreeDiff::Unexpected(entry_path(prefix, name, act)));
+                }
+            }
+        }
+        for (name, act) in &actual.entries {
+            if !self.entries.contains_key(name) {
+                diffs.push(TreeDiff::Unexpected(entry_path(prefix, name, act)));
+            }
+        }
+    }
+}
+
+fn entry_path(prefix: &str, name: &str, entry: &TreeEntry) -> String {
+    match entry {
+        TreeEntry::File(_) => format!("{prefix}{name}"),
+        TreeEntry::Folder(_) => format!("{prefix}{name}/"),
+    }
+}
+
+impl Display for TreeDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeDiff::Missing(path) => write!(f, "missing:    {path}"),
+            TreeDiff::Unexpected(path) => write!(f, "unexpected: {path}"),
+            TreeDiff::Changed {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "changed:    {path}\n  expected: {expected:?}\n  actual:   {actual:?}"
+            ),
+        }
+    }
+}
+
+/// Asserts that the content of `folder` matches the `expected` tree exactly,
+/// panicking with a list of missing, unexpected and changed entries.
+#[track_caller]
+pub fn assert_tree_eq(folder: &AbsoluteFolderPath, expected: &Tree) {
+    let actual = match Tree::read(folder) {
+        Ok(actual) => actual,
+        Err(e) => panic!("could not read the tree in {folder}: {e:#}"),
+    };
+    let diffs = expected.diff(&actual);
+    if !diffs.is_empty() {
+        let diffs = diffs
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!("the tree in {folder} differs from the expected:\n{diffs}");
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    os::{self, OsGroup},
+    SLASH,
+};
+
+use super::{PathInner, PathStr};
+
+/// The chars of `s` as compared when case doesn't matter.
+fn folded(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+pub(crate) fn cmp_str(a: &str, b: &str, case_sensitive: bool) -> Ordering {
+    if case_sensitive {
+        a.cmp(b)
+    } else {
+        folded(a).cmp(folded(b))
+    }
+}
+
+/// The key under which the strings equal by [cmp_str] are the same, which only
+/// allocates when case is ignored and `s` has chars that fold.
+pub(crate) fn folded_key(s: &str, case_sensitive: bool) -> Cow<'_, str> {
+    if case_sensitive || folded(s).eq(s.chars()) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(folded(s).collect())
+    }
+}
+
+impl<OS: OsGroup> PathStr<OS> {
+    pub(crate) fn eq_case(&self, other: &Self, case_sensitive: bool) -> bool {
+        if case_sensitive {
+            self.path == other.path
+        } else {
+            folded(&self.path).eq(folded(&other.path))
+        }
+    }
+
+    /// Compares with a path written as a string, segment by segment from the end
+    /// and without allocating. `None` if the string has to be expanded first, i.e.
+    /// it starts with `~` or `.` or has a `$` or `%` variable.
+    pub(crate) fn eq_str(&self, other: &str, case_sensitive: bool) -> Option<bool> {
+        let first = other.split(SLASH).next().unwrap_or_default();
+        if first == "~" || first == "." || other.contains(['$', '%']) {
+            return None;
+        }
+        let rest = match (os::win_drive(self.root()), os::win_drive(other)) {
+            (Some(drive), Some(other_drive)) if drive != other_drive => return Some(false),
+            (Some(drive), None) if drive != OS::drive_letter().ok()? => return Some(false),
+            (_, Some(_)) => &other[2..],
+            (_, None) => other,
+        };
+        if rest.starts_with(SLASH) != self.is_absolute() {
+            return Some(false);
+        }
+
+        let mut mine = self
+            .relative_part()
+            .rsplit(OS::SEP)
+            .filter(|s| !s.is_empty());
+        let mut matches = |segment: &str| {
+            mine.next()
+                .is_some_and(|s| cmp_str(s, segment, case_sensitive).is_eq())
+        };
+        // a `..` cancels out the segment before it, i.e. the next one from the end
+        let (mut up, mut count) = (0, 0);
+        for segment in rest.rsplit(SLASH) {
+            match segment {
+                "" | "." => {}
+                ".." => up += 1,
+                _ if up > 0 => up -= 1,
+                _ if matches(segment) => count += 1,
+                _ => return Some(false),
+            }
+        }
+        if self.is_relative() {
+            for _ in 0..up {
+                if !matches("..") {
+                    return Some(false);
+                }
+                count += 1;
+            }
+        }
+        if mine.next().is_some() {
+            return Some(false);
+        }
+        let last = rest.rsplit(SLASH).next().unwrap_or_default();
+        let is_folder = count == 0 || matches!(last, "" | "." | "..");
+        Some(is_folder == self.is_folder())
+    }
+
+    /// Orders by root and then segment by segment, so that `/a/b` comes before
+    /// `/a-b/`. Paths with the same segments, such as a file and a folder,
+    /// are ordered by their string to stay consistent with [eq_case](Self::eq_case).
+    pub(crate) fn cmp_case(&self, other: &Self, case_sensitive: bool) -> Ordering {
+        cmp_str(self.root(), other.root(), case_sensitive)
+            .then_with(|| {
+                let mut others = other.segments();
+                for segment in self.segments() {
+                    let Some(other) = others.next() else {
+                        return Ordering::Greater;
+                    };
+                    match cmp_str(segment, other, case_sensitive) {
+                        Ordering::Equal => {}
+                        ord => return ord,
+                    }
+                }
+                match others.next() {
+                    Some(_) => Ordering::Less,
+                    None => Ordering::Equal,
+                }
+            })
+            .then_with(|| cmp_str(&self.path, &other.path, case_sensitive))
+    }
+
+    pub(crate) fn hash_case<H: Hasher>(&self, state: &mut H, case_sensitive: bool) {
+        if case_sensitive {
+            self.path.hash(state);
+        } else {
+            folded(&self.path).for_each(|c| c.hash(state));
+            state.write_u8(0xff);
+        }
+    }
+}
+
+/// Compares as the file systems of the OS do, see [OsGroup::CASE_SENSITIVE].
+impl<OS: OsGroup> PartialEq for PathStr<OS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_case(other, OS::CASE_SENSITIVE)
+    }
+}
+
+impl<OS: OsGroup> Eq for PathStr<OS> {}
+
+impl<OS: OsGroup> PartialOrd for PathStr<OS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<OS: OsGroup> Ord for PathStr<OS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_case(other, OS::CASE_SENSITIVE)
+    }
+}
+
+impl<OS: OsGroup> Hash for PathStr<OS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash_case(state, OS::CASE_SENSITIVE)
+    }
+}
+
+impl<OS: OsGroup> PartialEq for PathInner<OS> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<OS: OsGroup> Eq for PathInner<OS> {}
@@ -0,0 +1,135 @@
+use std::ops::{Bound, RangeBounds};
+
+use anyhow::{ensure, Result};
+
+use crate::{ext::PathStrExt, os::OsGroup};
+
+use super::PathInner;
+
+/// Editing of segments by index, where the file/folder kind and the root
+/// of the path are kept.
+impl<OS: OsGroup> PathInner<OS> {
+    fn owned_segments(&self) -> Vec<String> {
+        self.segments().map(str::to_string).collect()
+    }
+
+    fn ensure_index(&self, index: usize, len: usize) -> Result<()> {
+        ensure!(
+            index < len,
+            "segment index {index} is out of bounds for {self} with {len} segments"
+        );
+        Ok(())
+    }
+
+    fn ensure_segment(segment: &str) -> Result<()> {
+        segment.assert_allowed_file_name()?;
+        segment.assert_allowed_path_component()?;
+        ensure!(
+            segment != "." && segment != "..",
+            "'{segment}' is not allowed as a segment"
+        );
+        Ok(())
+    }
+
+    /// A name in front of a `..` would cancel it out, which would leave the path
+    /// unnormalised, so the leading `..` of a relative path can't be edited.
+    fn ensure_after_up_levels(&self, index: usize, up_levels: usize, segment: &str) -> Result<()> {
+        ensure!(
+            index >= up_levels,
+            "cannot put '{segment}' in front of the leading '..' of {self}"
+        );
+        Ok(())
+    }
+
+    fn set_segments_keeping_kind(&mut self, segments: &[String]) {
+        let is_folder = self.is_folder();
+        let mut path = self.root().to_string();
+        path.push_str(&segments.join(OS::SEP_STR));
+        if is_folder && !segments.is_empty() {
+            path.push(OS::SEP);
+        }
+        self.path = path.into();
+    }
+
+    /// A relative folder from the segments in `range`.
+    pub(crate) fn slice_segments<R: RangeBounds<usize>>(&self, range: R) -> Result<Self> {
+        let segments = self.segments().collect::<Vec<_>>();
+        let start = match range.start_bound() {
+            Bound::Included(i) => *i,
+            Bound::Excluded(i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(i) => i + 1,
+            Bound::Excluded(i) => *i,
+            Bound::Unbounded => segments.len(),
+        };
+        ensure!(
+            start <= end && end <= segments.len(),
+            "segment range {start}..{end} is out of bounds for {self} with {} segments",
+            segments.len()
+        );
+        ensure!(
+            start < end,
+            "the segment range {start}..{end} of {self} is empty"
+        );
+        let mut path = segments[start..end].join(OS::SEP_STR);
+        path.push(OS::SEP);
+        Self::new_expanded(&path)
+    }
+
+    pub(crate) fn replace_segment(&mut self, index: usize, segment: &str) -> Result<()> {
+        Self::ensure_segment(segment)?;
+        let mut segments = self.owned_segments();
+        self.ensure_index(index, segments.len())?;
+        self.ensure_after_up_levels(index + 1, self.up_levels(), segment)?;
+        segments[index] = segment.to_string();
+        self.set_segments_keeping_kind(&segments);
+        Ok(())
+    }
+
+    /// Inserts the segment before the one at `index`, or last if `index`
+    /// is the number of segments.
+    pub(crate) fn insert_segment(&mut self, index: usize, segment: &str) -> Result<()> {
+        Self::ensure_segment(segment)?;
+        let mut segments = self.owned_segments();
+        self.ensure_index(index, segments.len() + 1)?;
+        self.ensure_after_up_levels(index, self.up_levels(), segment)?;
+        segments.insert(index, segment.to_string());
+        self.set_segments_keeping_kind(&segments);
+        Ok(())
+    }
+
+    pub(crate) fn remove_segment(&mut self, index: usize) -> Result<()> {
+        let mut segments = self.owned_segments();
+        self.ensure_index(index, segments.len())?;
+        ensure!(
+            segments.len() > 1 || (self.is_absolute() && self.is_folder()),
+            "cannot remove the only segment of {self}"
+        );
+        segments.remove(index);
+        self.set_segments_keeping_kind(&segments);
+        Ok(())
+    }
+
+    /// Splits into the folder with the segments before `index` and the
+    /// relative path with the rest, which keeps the kind of this path.
+    pub(crate) fn split_at_segment(&self, index: usize) -> Result<(Self, Self)> {
+        let segments = self.owned_segments();
+        self.ensure_index(index, segments.len())?;
+        ensure!(
+            index > 0 || self.is_absolute(),
+            "splitting {self} at {index} leaves an empty folder"
+        );
+        let mut folder = self.root().to_string();
+        for segment in &segments[..index] {
+            folder.push_str(segment);
+            folder.push(OS::SEP);
+        }
+        let mut rest = segments[index..].join(OS::SEP_STR);
+        if self.is_folder() {
+            rest.push(OS::SEP);
+        }
+        Ok((Self::from_string(folder), Self::new_expanded(&rest)?))
+    }
+}
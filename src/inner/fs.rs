@@ -0,0 +1,156 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, ensure, Context, Result};
+
+use crate::{
+    ext::{PathExt, PathStrExt},
+    os::{self, OsGroup},
+    SLASH,
+};
+
+use super::PathInner;
+
+impl<OS: OsGroup> PathInner<OS> {
+    /// Resolves all symlinks and returns the path the OS considers canonical.
+    /// The result is a folder (ends with a slash) if it points to a directory.
+    /// A `..` is already removed lexically when the path is created, so in
+    /// `/a/link/../b` it never follows the link, see [new_canonical](Self::new_canonical).
+    pub(crate) fn canonicalized(&self) -> Result<Self> {
+        Self::canonical_of(self.as_path(), self.as_str())
+    }
+
+    /// Canonicalizes the path as written, where `~`, `.` and environment variables
+    /// are expanded but nothing is normalised, so that the OS applies a `..` after
+    /// a symlink to the target of the link.
+    pub(crate) fn new_canonical(path: &str) -> Result<Self> {
+        let expanded = os::expand::<OS>(path)?;
+        Self::canonical_of(Path::new(&*expanded), path)
+    }
+
+    fn canonical_of(path: &Path, display: &str) -> Result<Self> {
+        let canonical = fs::canonicalize(path)
+            .with_context(|| format!("could not canonicalize path: {display}"))?;
+        let is_dir = canonical.is_dir();
+        let mut inner = Self::new_expanded(os::remove_verbatim_prefix(canonical.try_to_str()?))?;
+        if is_dir && !inner.is_folder() {
+            inner.path.edit(|path| path.push(OS::SEP));
+        }
+        Ok(inner)
+    }
+
+    /// Checks if both paths point to the same file or folder on disk. On unix
+    /// it compares device and inode, on Windows volume serial and file index.
+    pub(crate) fn is_same_file(&self, other: &Self) -> Result<bool> {
+        same_file::is_same_file(self.as_path(), other.as_path())
+            .with_context(|| format!("could not compare files: {self} and {other}"))
+    }
+
+    /// Adds the trailing slash if it is missing and the path is an existing folder.
+    pub(crate) fn infer_folder_fs(&mut self) {
+        if !self.is_folder() && self.as_path().is_dir() {
+            self.path.edit(|path| path.push(OS::SEP));
+        }
+    }
+
+    /// Like `ensure_folder` but accepts a path without a trailing slash
+    /// if it is an existing folder, in which case the slash is added.
+    pub(crate) fn ensure_folder_fs(&mut self) -> Result<()> {
+        if self.is_folder() {
+            return Ok(());
+        }
+        let p = self.as_path();
+        ensure!(!p.is_file(), "path is a file, not a folder: {self}");
+        ensure!(
+            p.is_dir(),
+            "path is not an existing folder and doesn't end with a slash: {self}"
+        );
+        self.path.edit(|path| path.push(OS::SEP));
+        Ok(())
+    }
+
+    /// Like `ensure_file` but also rejects existing folders.
+    pub(crate) fn ensure_file_fs(&self) -> Result<()> {
+        ensure!(
+            !self.as_path().is_dir(),
+            "path is a folder, not a file: {self}"
+        );
+        self.ensure_file()
+    }
+
+    pub(crate) fn as_path_without_slash(&self) -> &Path {
+        let end = self.path.len() - if self.path.ends_with(SLASH) { 1 } else { 0 };
+        let end = end.max(self.relative_start());
+        Path::new(&self.path[..end])
+    }
+
+    /// The folder that contains the file or folder, i.e. `/a/` for both `/a/b` and `/a/b/`.
+    pub(crate) fn containing_folder(&self) -> Self {
+        let end = self.path.len() - if self.path.ends_with(SLASH) { 1 } else { 0 };
+        let start = self.relative_start().min(end);
+        let end = self.path[..end].after_last_slash_from(start);
+        PathInner {
+            path: self.path[..end].to_owned().into(),
+            t: self.t,
+        }
+    }
+
+    /// Ensures that the path can be created, i.e. that the containing folder
+    /// exists and is writable and that the path isn't taken by another kind.
+    pub(crate) fn ensure_creatable_fs(&self) -> Result<()> {
+        // a trailing slash makes the OS expect a folder, so it's removed
+        // to find out what's there
+        let p = self.as_path_without_slash();
+        if self.is_folder() {
+            ensure!(!p.is_file(), "path is a file, not a folder: {self}");
+        } else {
+            ensure!(!p.is_dir(), "path is a folder, not a file: {self}");
+        }
+        let folder = self.containing_folder();
+        ensure!(
+            folder.as_path().is_dir(),
+            "folder doesn't exist: {folder} (needed for {self})"
+        );
+        ensure!(
+            is_writable(folder.as_path()),
+            "folder is not writable: {folder} (needed for {self})"
+        );
+        Ok(())
+    }
+
+    /// Ensures that nothing exists at the path, not even a broken symlink.
+    pub(crate) fn ensure_fresh_fs(&self) -> Result<()> {
+        if fs::symlink_metadata(self.as_path_without_slash()).is_ok() {
+            bail!("path already exists: {self}");
+        }
+        Ok(())
+    }
+
+    /// Creates the folder, or the containing folder of a file, including
+    /// all missing parent folders.
+    pub(crate) fn ensure_dir_fs(&self) -> Result<()> {
+        let folder = if self.is_folder() {
+            self.clone()
+        } else {
+            self.containing_folder()
+        };
+        fs::create_dir_all(folder.as_path())
+            .with_context(|| format!("could not create folder: {folder}"))
+    }
+}
+
+#[cfg(unix)]
+fn is_writable(path: &Path) -> bool {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    match CString::new(path.as_os_str().as_bytes()) {
+        Ok(path) => unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 },
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_writable(path: &Path) -> bool {
+    fs::metadata(path)
+        .map(|meta| !meta.permissions().readonly())
+        .unwrap_or(false)
+}
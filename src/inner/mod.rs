@@ -1,11 +1,18 @@
 #[cfg(test)]
 mod test;
 
+mod case;
+mod edit;
+mod fs;
+mod natural;
 mod path;
 mod public;
 mod str_values;
+mod string;
 mod traits;
 
-pub(crate) use path::PathInner;
+pub(crate) use case::{cmp_str, folded_key};
+pub(crate) use path::{PathInner, PathStr};
 pub use str_values::StrValues;
-pub use traits::TryExist;
+pub use string::PathString;
+pub use traits::{Join, TryExist, TryFromFs, TryOutput};
@@ -0,0 +1,92 @@
+use std::{cmp::Ordering, iter::Peekable, str::Chars};
+
+use crate::os::OsGroup;
+
+use super::PathStr;
+
+/// Takes the run of digits at the start of `chars`.
+fn digits(chars: &mut Peekable<Chars<'_>>) -> String {
+    let mut run = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        run.push(c);
+    }
+    run
+}
+
+/// Compares two digit runs by their value, however long they are.
+fn cmp_numbers(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn cmp_chars(a: char, b: char, case_sensitive: bool) -> Ordering {
+    if case_sensitive {
+        a.cmp(&b)
+    } else {
+        a.to_lowercase().cmp(b.to_lowercase())
+    }
+}
+
+/// Compares the strings with runs of digits compared by their value, so that
+/// `file2` comes before `file10`. Strings that only differ in leading zeros are equal.
+pub(crate) fn cmp_natural_str(a: &str, b: &str, case_sensitive: bool) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        let ord = match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                cmp_numbers(&digits(&mut a), &digits(&mut b))
+            }
+            (Some(x), Some(y)) => {
+                a.next();
+                b.next();
+                cmp_chars(x, y, case_sensitive)
+            }
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+impl<OS: OsGroup> PathStr<OS> {
+    /// True if the segment at `index` is a folder, i.e. it's followed by more
+    /// segments or is the last one of a folder path.
+    fn is_folder_at(&self, index: usize, depth: usize) -> bool {
+        index + 1 < depth || self.is_folder()
+    }
+
+    /// Orders segment by segment like [cmp_case](Self::cmp_case), but with
+    /// [cmp_natural_str] for each segment. With `folders_first` a folder comes
+    /// before a file in the same folder, which is checked at every segment before
+    /// the names so that the order stays transitive.
+    pub(crate) fn cmp_natural(
+        &self,
+        other: &Self,
+        case_sensitive: bool,
+        folders_first: bool,
+    ) -> Ordering {
+        let (depth, other_depth) = (self.depth(), other.depth());
+        let ord = self.root().cmp(other.root()).then_with(|| {
+            let segments = self.segments().zip(other.segments()).enumerate();
+            for (i, (a, b)) in segments {
+                let kinds = match folders_first {
+                    true => other
+                        .is_folder_at(i, other_depth)
+                        .cmp(&self.is_folder_at(i, depth)),
+                    false => Ordering::Equal,
+                };
+                let ord = kinds.then_with(|| cmp_natural_str(a, b, case_sensitive));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            depth.cmp(&other_depth)
+        });
+        ord.then_with(|| self.cmp_case(other, case_sensitive))
+    }
+}
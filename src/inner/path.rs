@@ -1,9 +1,9 @@
 use anyhow::{ensure, Result};
 use serde::Deserialize;
-use std::ops::Range;
+use std::borrow::{Borrow, Cow};
+use std::ops::{Deref, Range};
 use std::{marker::PhantomData, path::Path};
 
-use crate::RelativeFolderPath;
 use crate::{
     ext::{PathExt, PathStrExt},
     iter::{Extensions, InnerSegmentIter},
@@ -11,17 +11,19 @@ use crate::{
     SLASH,
 };
 
-use super::StrValues;
+use super::{PathString, StrValues};
 
-#[derive(Deserialize, PartialEq, Eq)]
+#[derive(Deserialize)]
 #[serde(transparent)]
+#[repr(transparent)]
 pub(crate) struct PathInner<OS> {
     /// an absolute path is guaranteed to start with
     /// - on win: `<drive-letter>:\` or `\`
     /// - on *nix: `/`
+    ///
     /// a path is guaranteed to have one and only one
     /// path separator (win: `\`, otherwise: `/`) per segment
-    pub(crate) path: String,
+    pub(crate) path: PathString,
     pub(crate) t: PhantomData<OS>,
 }
 
@@ -34,38 +36,167 @@ impl<OS> Clone for PathInner<OS> {
     }
 }
 
+/// The borrowed form of [PathInner], like `str` for `String`. All functions
+/// that only read the path are here and reachable from a PathInner by deref.
+#[repr(transparent)]
+pub(crate) struct PathStr<OS> {
+    pub(crate) t: PhantomData<OS>,
+    pub(crate) path: str,
+}
+
+impl<OS> Deref for PathInner<OS> {
+    type Target = PathStr<OS>;
+
+    fn deref(&self) -> &PathStr<OS> {
+        PathStr::from_str(&self.path)
+    }
+}
+
+impl<OS> Borrow<PathStr<OS>> for PathInner<OS> {
+    fn borrow(&self) -> &PathStr<OS> {
+        self
+    }
+}
+
+impl<OS> ToOwned for PathStr<OS> {
+    type Owned = PathInner<OS>;
+
+    fn to_owned(&self) -> PathInner<OS> {
+        PathInner {
+            path: self.path.to_string().into(),
+            t: PhantomData,
+        }
+    }
+}
+
+impl<OS> PathStr<OS> {
+    /// Views a valid path string as a PathStr, which is sound because
+    /// PathStr is a transparent wrapper around its str.
+    pub(crate) fn from_str(path: &str) -> &Self {
+        unsafe { &*(path as *const str as *const Self) }
+    }
+}
+
 impl<OS: OsGroup> PathInner<OS> {
-    pub(crate) fn empty() -> Self {
+    pub(crate) fn from_string(path: String) -> Self {
         Self {
-            path: String::new(),
+            path: path.into(),
             t: PhantomData,
         }
     }
 
-    pub(crate) fn new(path: &str) -> Result<Self> {
-        let mut inner = PathInner::empty();
+    /// Views the string of a path as a PathInner, which is sound because
+    /// PathInner is a transparent wrapper around its string.
+    pub(crate) fn from_path_string_mut(path: &mut PathString) -> &mut Self {
+        unsafe { &mut *(path as *mut PathString as *mut Self) }
+    }
 
+    pub(crate) fn new(path: &str) -> Result<Self> {
         let path = os::expand::<OS>(path)?;
+        Self::new_expanded(&path)
+    }
+
+    /// Creates a path from a string that doesn't need environment variables,
+    /// `~` or `.` expanded, such as one returned by the OS.
+    pub(crate) fn new_expanded(path: &str) -> Result<Self> {
+        let mut inner = String::new();
 
-        let path = OS::process_drive_letter(&path, &mut inner.path)?;
+        let path = OS::process_drive_letter(path, &mut inner)?;
         if path.starts_with(SLASH) {
-            inner.path.push(OS::SEP)
+            inner.push(OS::SEP)
         }
         let iter = InnerSegmentIter::new(path);
 
         for (segment, has_more) in iter {
-            inner.push_segment(segment)?;
+            segment.assert_allowed_path_component()?;
+            inner.push_str(segment);
             if has_more {
-                inner.path.push(OS::SEP);
+                inner.push(OS::SEP);
             }
         }
-        Ok(inner)
+        Ok(Self::from_string(inner))
     }
 
     pub(crate) fn new_from_path(path: &Path) -> Result<Self> {
         Self::new(path.try_to_str()?)
     }
 
+    pub(crate) fn set_extensions<E: StrValues>(&mut self, extensions: E) {
+        let end = self.file_stem_range().end;
+        let ext = extensions.join_strings(".");
+        self.path.edit(|path| {
+            path.truncate(end);
+            if !ext.is_empty() {
+                path.push('.');
+                path.push_str(&ext)
+            }
+        })
+    }
+
+    pub(crate) fn push_segments<S: StrValues>(&mut self, segments: S) -> Result<()> {
+        for i in 0..segments.string_count() {
+            segments.string_at(i).assert_allowed_path_component()?;
+        }
+        self.path.edit(|path| {
+            for i in 0..segments.string_count() {
+                path.push_str(segments.string_at(i));
+            }
+        });
+        Ok(())
+    }
+
+    pub(crate) fn pop_last_segment(&mut self) {
+        let rel_start = self.relative_start();
+        let end = if self.path[rel_start..].ends_with(OS::SEP) {
+            self.path[rel_start..self.path.len() - 1].rfind(OS::SEP)
+        } else {
+            self.path[rel_start..].rfind(OS::SEP)
+        };
+        if let Some(end) = end {
+            self.path.edit(|path| path.truncate(rel_start + end));
+        }
+    }
+
+    pub(crate) fn set_file_name(&mut self, file_name: &str) -> Result<()> {
+        file_name.assert_allowed_file_name()?;
+        let file_start = self.file_name_start();
+        self.path.edit(|path| {
+            path.truncate(file_start);
+            path.push_str(file_name);
+        });
+        Ok(())
+    }
+
+    pub(crate) fn set_file_stem(&mut self, file_stem: &str) -> Result<()> {
+        ensure!(!file_stem.is_empty(), "An empty file stem is not valid");
+        let range = self.file_stem_range();
+        let mut path = self.path[..range.start].to_string();
+        path.push_str(file_stem);
+        path.push_str(&self.path[range.end..]);
+        self.path = path.into();
+        Ok(())
+    }
+
+    /// The deepest folder that contains all the paths, where a folder contains itself.
+    pub(crate) fn common_ancestor<'a, I>(paths: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        OS: 'a,
+    {
+        let mut paths = paths.into_iter();
+        let mut common = paths.next()?.ancestor_folders().into_iter().next()?;
+        for path in paths {
+            let folder = path.ancestor_folders().into_iter().next()?;
+            while !folder.starts_with_segments(&common) {
+                let parent = common.ancestor_folders().into_iter().nth(1)?;
+                common = parent;
+            }
+        }
+        Some(common)
+    }
+}
+
+impl<OS: OsGroup> PathStr<OS> {
     pub(super) fn as_contracted(&self, do_contract: bool) -> (Option<char>, &str) {
         if do_contract && self.is_absolute() {
             match os::contract::<OS>(&self.path) {
@@ -73,7 +204,7 @@ impl<OS: OsGroup> PathInner<OS> {
                 Err(_) => (None, &self.path),
             }
         } else {
-            (None, self.path.as_str())
+            (None, &self.path)
         }
     }
 
@@ -89,8 +220,13 @@ impl<OS: OsGroup> PathInner<OS> {
         !self.is_folder()
     }
 
+    /// True if the path ends with a slash, or is the empty relative path, i.e. the
+    /// folder it is relative to.
     pub(crate) fn is_folder(&self) -> bool {
-        self.path.ends_with(SLASH) || self.path == "." || self.path == "~"
+        self.path.ends_with(SLASH)
+            || &self.path == "."
+            || &self.path == "~"
+            || self.relative_part().is_empty() && self.is_relative()
     }
 
     pub(crate) fn ensure_absolute(&self) -> Result<()> {
@@ -116,6 +252,7 @@ impl<OS: OsGroup> PathInner<OS> {
         );
         Ok(())
     }
+
     pub(crate) fn ensure_folder(&self) -> Result<()> {
         ensure!(
             self.is_folder(),
@@ -124,14 +261,14 @@ impl<OS: OsGroup> PathInner<OS> {
         Ok(())
     }
 
-    pub(crate) fn relative_from(&self, segments: usize) -> Self {
+    pub(crate) fn relative_from(&self, segments: usize) -> PathInner<OS> {
         let path = self
             .segments()
             .skip(segments)
             .collect::<Vec<_>>()
-            .join(&OS::SEP.to_string());
+            .join(OS::SEP_STR);
 
-        Self { path, t: self.t }
+        PathInner::from_string(path)
     }
 
     pub(crate) fn relative_part(&self) -> &str {
@@ -142,76 +279,27 @@ impl<OS: OsGroup> PathInner<OS> {
         OS::start_of_relative_path(&self.path)
     }
 
-    pub(crate) fn join(&mut self, dir: &RelativeFolderPath) {
-        if !self.path.ends_with(OS::SEP) {
-            self.path.push(OS::SEP);
-        }
-        self.path.push_str(&dir.0.path);
+    pub(crate) fn extensions(&self) -> Extensions<'_> {
+        Extensions::new(self.full_extension().unwrap_or_default())
     }
 
-    pub(crate) fn joining(&self, dir: &RelativeFolderPath) -> Self {
-        let mut me = self.clone();
-        me.join(dir);
-        me
+    pub(crate) fn extension(&self) -> Option<&str> {
+        self.extensions().last()
     }
 
-    pub(crate) fn extensions(&self) -> Extensions {
-        Extensions::new(&self.path)
-    }
-
-    pub(crate) fn set_extensions<E: StrValues>(&mut self, extensions: E) {
-        if let Some(last_slash_index) = self.path.rfind(SLASH) {
-            if let Some(first_dot_index) = self.path[last_slash_index..].find('.') {
-                self.path.truncate(last_slash_index + first_dot_index);
-            }
-        } else if let Some(first_dot_index) = self.path.find('.') {
-            self.path.truncate(first_dot_index)
-        }
-        let ext = extensions.join_strings(".");
+    /// everything after the first dot that follows the stem, i.e. `tar.gz`
+    /// for `archive.tar.gz` and `local` for `.env.local`. Trailing dots are
+    /// not an extension, so `file.tar.` has `tar` and `file.` none.
+    pub(crate) fn full_extension(&self) -> Option<&str> {
+        let end = self.file_stem_range().end;
+        let ext = self.path.get(end + 1..)?.trim_end_matches('.');
         if ext.is_empty() {
-            return;
-        }
-        self.path.push('.');
-        self.path.push_str(&extensions.join_strings("."))
-    }
-
-    pub(crate) fn push_segment(&mut self, segment: &str) -> Result<()> {
-        segment.assert_allowed_path_component()?;
-        self.path.push_str(segment);
-        Ok(())
-    }
-
-    pub(crate) fn push_segments<S: StrValues>(&mut self, segments: S) -> Result<()> {
-        for i in 0..segments.string_count() {
-            self.push_segment(segments.string_at(i))?;
-        }
-        Ok(())
-    }
-
-    pub fn pushing_segments<S: StrValues>(&self, segments: S) -> Result<Self> {
-        let mut me = self.clone();
-        me.push_segments(segments)?;
-        Ok(me)
-    }
-
-    pub(crate) fn pop_last_segment(&mut self) {
-        let rel_start = self.relative_start();
-        let end = if self.path[rel_start..].ends_with(OS::SEP) {
-            self.path[rel_start..self.path.len() - 1].rfind(OS::SEP)
+            None
         } else {
-            self.path[rel_start..].rfind(OS::SEP)
-        };
-        if let Some(end) = end {
-            self.path.truncate(rel_start + end);
+            Some(ext)
         }
     }
 
-    pub(crate) fn popping_last_segment(&self) -> Self {
-        let mut me = self.clone();
-        me.pop_last_segment();
-        me
-    }
-
     fn file_name_start(&self) -> usize {
         let rel_start = self.relative_start();
         self.path.after_last_slash_from(rel_start)
@@ -221,24 +309,14 @@ impl<OS: OsGroup> PathInner<OS> {
         &self.path[self.file_name_start()..]
     }
 
-    pub(crate) fn set_file_name(&mut self, file_name: &str) -> Result<()> {
-        file_name.assert_allowed_file_name()?;
-        let file_start = self.file_name_start();
-        self.path.truncate(file_start);
-        self.path.push_str(file_name);
-        Ok(())
-    }
-
-    pub(crate) fn with_file_name(&self, file_name: &str) -> Result<Self> {
-        let mut me = self.clone();
-        me.set_file_name(file_name)?;
-        Ok(me)
-    }
-
+    /// The stem runs from the start of the file name to the first dot that
+    /// isn't a leading one, so that `.bashrc` and `.env.local` have the
+    /// stems `.bashrc` and `.env`.
     pub(crate) fn file_stem_range(&self) -> Range<usize> {
-        let rel = self.relative_start();
-        let start = self.path.after_last_slash_from(rel);
-        let end = self.path.first_dot_from(start);
+        let start = self.file_name_start();
+        let leading_dots =
+            self.path[start..].len() - self.path[start..].trim_start_matches('.').len();
+        let end = self.path.first_dot_from(start + leading_dots);
         start..end
     }
 
@@ -246,63 +324,161 @@ impl<OS: OsGroup> PathInner<OS> {
         &self.path[self.file_stem_range()]
     }
 
-    pub(crate) fn set_file_stem(&mut self, file_stem: &str) -> Result<()> {
-        ensure!(!file_stem.is_empty(), "An empty file stem is not valid");
-        let range = self.file_stem_range();
-        let mut path = self.path[..range.start].to_string();
-        path.push_str(file_stem);
-        path.push_str(&self.path[range.end..]);
-        self.path = path;
-        Ok(())
+    /// The folder the file is in, which is empty for a relative file without folders.
+    pub(crate) fn drop_file(&self) -> &Self {
+        self.prefix(self.file_name_start())
     }
 
-    pub(crate) fn drop_file(&self) -> Self {
-        let start = self.file_name_start();
+    fn prefix(&self, end: usize) -> &Self {
+        Self::from_str(&self.path[..end])
+    }
+
+    fn with_path(&self, path: &str) -> PathInner<OS> {
         PathInner {
-            path: self.path[..start].to_owned(),
+            path: path.to_string().into(),
             t: self.t,
         }
     }
 
-    pub(crate) fn with_file_stem(&self, file_stem: &str) -> Result<Self> {
-        let mut me = self.clone();
-        me.set_file_stem(file_stem)?;
-        Ok(me)
+    /// Adds a file or folder name to a folder path.
+    pub(crate) fn child(&self, name: &str, is_folder: bool) -> Result<PathInner<OS>> {
+        name.assert_allowed_file_name()?;
+        name.assert_allowed_path_component()?;
+        let mut path = self.path.to_string();
+        if !path.is_empty() && !path.ends_with(OS::SEP) {
+            path.push(OS::SEP);
+        }
+        path.push_str(name);
+        if is_folder {
+            path.push(OS::SEP);
+        }
+        Ok(PathInner::from_string(path))
     }
 
-    pub(crate) fn with_root(&self, root: &str) -> Self {
-        PathInner {
-            path: root.to_owned() + &self.path,
-            t: self.t,
+    pub(crate) fn remove_root(&self, root: &str) -> Option<PathInner<OS>> {
+        self.path.strip_prefix(root).map(|s| self.with_path(s))
+    }
+
+    /// The folder containing this path, borrowed from it unless the path ends
+    /// with `..` and the parent is one more level up.
+    pub(crate) fn parent(&self) -> Option<Cow<'_, Self>> {
+        let start = self.relative_start();
+
+        let ends_with_slash = self.path[start..].ends_with(OS::SEP);
+        let end = self.path.len() - if ends_with_slash { 1 } else { 0 };
+
+        if self.path[start..end].rsplit(OS::SEP).next() == Some("..") {
+            // the parent of a path that climbs up is one more level up
+            let mut path = self.path[..end].to_string();
+            path.push(OS::SEP);
+            path.push_str("..");
+            path.push(OS::SEP);
+            return Some(Cow::Owned(PathInner::from_string(path)));
         }
+
+        self.path[start..end]
+            .rfind(OS::SEP)
+            .map(|prev_sep| Cow::Borrowed(self.prefix(start + prev_sep + 1)))
     }
-    fn with_path(&self, path: &str) -> Self {
-        PathInner {
-            path: path.to_string(),
-            t: self.t,
+
+    /// The folder with the first `count` segments, `None` if the path doesn't
+    /// have that many folder segments.
+    pub(crate) fn leading(&self, count: usize) -> Option<&Self> {
+        let start = self.relative_start();
+        let mut end = start;
+        for _ in 0..count {
+            end += self.path[end..].find(OS::SEP)? + 1;
         }
+        Some(self.prefix(end))
     }
 
-    pub(crate) fn appending(&self, path: &str) -> Self {
-        PathInner {
-            path: self.path.clone() + path,
-            t: self.t,
+    /// The folders containing this path, nearest first and ending with the root.
+    /// For a folder the path itself is the first.
+    pub(crate) fn ancestor_folders(&self) -> Vec<PathInner<OS>> {
+        let start = self.relative_start();
+        let mut segments = self.segments().collect::<Vec<_>>();
+        if self.is_file() {
+            segments.pop();
+        }
+        let mut ancestors = Vec::with_capacity(segments.len() + 1);
+        loop {
+            let mut path = self.path[..start].to_string();
+            for segment in &segments {
+                path.push_str(segment);
+                path.push(OS::SEP);
+            }
+            ancestors.push(PathInner::from_string(path));
+            if segments.pop().is_none() {
+                break;
+            }
         }
+        ancestors
     }
 
-    pub(crate) fn remove_root(&self, root: &str) -> Option<Self> {
-        self.path.strip_prefix(root).map(|s| self.with_path(s))
+    /// The drive and leading separator of an absolute path, empty for a relative one.
+    pub(crate) fn root(&self) -> &str {
+        &self.path[..self.relative_start()]
     }
 
-    pub(crate) fn parent(&self) -> Option<Self> {
-        let start = self.relative_start();
+    pub(crate) fn depth(&self) -> usize {
+        self.segments().count()
+    }
 
-        let ends_with_slash = self.path[start..].ends_with(OS::SEP);
-        let end = self.path.len() - if ends_with_slash { 1 } else { 0 };
+    /// True if the paths have the same root and all the segments of `prefix`
+    /// are the first segments of this path.
+    pub(crate) fn starts_with_segments(&self, prefix: &Self) -> bool {
+        if self.root() != prefix.root() {
+            return false;
+        }
+        let mut segments = self.segments();
+        prefix.segments().all(|p| segments.next() == Some(p))
+    }
 
-        self.path[start..end].rfind(OS::SEP).map(|prev_sep| {
-            let path = self.path[..prev_sep + 2].to_string();
-            Self { path, t: self.t }
-        })
+    /// True if all the segments of `suffix` are the last segments of this path.
+    /// A folder suffix only matches a folder.
+    pub(crate) fn ends_with_segments(&self, suffix: &Self) -> bool {
+        if suffix.is_folder() && !self.is_folder() {
+            return false;
+        }
+        let mut segments = self.segments();
+        suffix
+            .segments()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .all(|s| segments.next_back() == Some(s))
+    }
+
+    /// The number of leading `..` segments
+    pub(crate) fn up_levels(&self) -> usize {
+        self.segments().take_while(|s| *s == "..").count()
+    }
+
+    /// Applies the segments of this relative path to the folder `base`, where every
+    /// `..` removes the last segment of `base`. Above a relative `base` the `..` are
+    /// kept, and a result without segments is the empty relative path.
+    pub(crate) fn resolve(&self, base: &Self) -> Result<PathInner<OS>> {
+        let mut segments = base.segments().collect::<Vec<_>>();
+        for segment in self.segments() {
+            match segments.last() {
+                _ if segment != ".." => segments.push(segment),
+                Some(last) if *last != ".." => {
+                    segments.pop();
+                }
+                _ => {
+                    ensure!(
+                        base.is_relative(),
+                        "path climbs above the root: {self} (resolved against {base})"
+                    );
+                    segments.push(segment);
+                }
+            }
+        }
+        let mut path = base.path[..base.relative_start()].to_string();
+        path.push_str(&segments.join(OS::SEP_STR));
+        if self.is_folder() && !segments.is_empty() {
+            path.push(OS::SEP);
+        }
+        Ok(PathInner::from_string(path))
     }
 }
@@ -2,10 +2,10 @@ use std::path::Path;
 
 use crate::{iter::Segments, os::OsGroup};
 
-use super::PathInner;
+use super::PathStr;
 
-impl<OS: OsGroup> PathInner<OS> {
-    pub fn segments(&self) -> Segments {
+impl<OS: OsGroup> PathStr<OS> {
+    pub fn segments(&self) -> Segments<'_> {
         Segments::new(self.relative_part())
     }
 
@@ -0,0 +1,81 @@
+use std::ops::Deref;
+
+use serde::{Deserialize, Deserializer};
+
+/// The string of a path. With the feature `arc-str` it is an `Arc<str>`, which
+/// makes cloning a path O(1) at the cost of a copy on each edit.
+#[doc(hidden)]
+#[derive(Clone, Default)]
+pub struct PathString(
+    #[cfg(not(feature = "arc-str"))] String,
+    #[cfg(feature = "arc-str")] std::sync::Arc<str>,
+);
+
+impl PathString {
+    /// Changes the string in place, or a copy of it that replaces the shared one.
+    /// Each edit of a path should be done with one call.
+    pub(crate) fn edit<R>(&mut self, f: impl FnOnce(&mut String) -> R) -> R {
+        #[cfg(not(feature = "arc-str"))]
+        {
+            f(&mut self.0)
+        }
+        #[cfg(feature = "arc-str")]
+        {
+            let mut path = String::with_capacity(self.0.len() + 16);
+            path.push_str(&self.0);
+            let result = f(&mut path);
+            self.0 = path.into();
+            result
+        }
+    }
+
+    pub(crate) fn into_string(self) -> String {
+        #[cfg(not(feature = "arc-str"))]
+        {
+            self.0
+        }
+        #[cfg(feature = "arc-str")]
+        {
+            self.0.to_string()
+        }
+    }
+}
+
+impl From<String> for PathString {
+    fn from(path: String) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self(path.into())
+    }
+}
+
+impl Deref for PathString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for PathString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl std::fmt::Debug for PathString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl PartialEq<str> for PathString {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&str> for PathString {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
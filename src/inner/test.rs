@@ -138,6 +138,9 @@ fn test_debug() {
 
     assert_eq!(format!("{p1:?}"), "./dir/hi");
     assert_eq!(format!("{p1:#?}"), "/var/test/dir/hi");
+    let p1 = PathInner::<LinTestOS>::new("/var/test2/hi").unwrap();
+    assert_eq!(format!("{p1:?}"), "/var/test2/hi");
+    assert_eq!(format!("{p1}"), "/var/test2/hi");
 
     let p1 = PathInner::<WinTestOS>::new(".\\dir\\hi").unwrap();
 
@@ -165,6 +168,16 @@ fn test_extensions() {
     let mut p = PathInner::<LinTestOS>::new("some/file.").unwrap();
     let exts = p.extensions().collect::<Vec<_>>();
     assert_eq!(exts, Vec::<String>::new());
+    assert_eq!((p.extension(), p.full_extension()), (None, None));
+
+    let dot = PathInner::<LinTestOS>::new("file.tar.").unwrap();
+    assert_eq!(dot.extensions().collect::<Vec<_>>(), ["tar"]);
+    assert_eq!(dot.extension(), Some("tar"));
+    assert_eq!(dot.full_extension(), Some("tar"));
+    let dots = PathInner::<LinTestOS>::new("file.tar.gz..").unwrap();
+    assert_eq!(dots.extensions().collect::<Vec<_>>(), ["tar", "gz"]);
+    assert_eq!(dots.extension(), Some("gz"));
+    assert_eq!(dots.full_extension(), Some("tar.gz"));
 
     p.set_extensions(vec!["txt", "bz"]);
     assert_eq!(format!("{p:?}"), "some/file.txt.bz");
@@ -240,6 +253,28 @@ fn test_file_stem() {
     assert_eq!(p.file_stem(), "file");
 }
 
+#[test]
+fn test_ancestor_folders() {
+    let ancestors = |p: &str| {
+        PathInner::<LinTestOS>::new(p)
+            .unwrap()
+            .ancestor_folders()
+            .into_iter()
+            .map(|a| a.path)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ancestors("/a/b/"), vec!["/a/b/", "/a/", "/"]);
+    assert_eq!(ancestors("/a/b.txt"), vec!["/a/", "/"]);
+    assert_eq!(ancestors("/"), vec!["/"]);
+
+    let p = PathInner::<WinTestOS>::new(r"C:\a\b\").unwrap();
+    let paths = p.ancestor_folders().into_iter().map(|a| a.path);
+    assert_eq!(
+        paths.collect::<Vec<_>>(),
+        vec![r"C:\a\b\", r"C:\a\", r"C:\"]
+    );
+}
+
 #[test]
 fn test_parent() {
     let p = PathInner::<LinTestOS>::new("/parent/dir").unwrap();
@@ -254,3 +289,381 @@ fn test_parent() {
     let parent = p.parent().unwrap();
     assert_eq!(format!("{parent:?}"), "/parent/");
 }
+
+#[test]
+fn test_dotfiles() {
+    let p = PathInner::<LinTestOS>::new("/home/.bashrc").unwrap();
+    assert_eq!(p.file_stem(), ".bashrc");
+    assert_eq!(p.extension(), None);
+    assert_eq!(p.full_extension(), None);
+    assert_eq!(p.extensions().count(), 0);
+
+    let mut p = PathInner::<LinTestOS>::new("dir.d/.env.local").unwrap();
+    assert_eq!(p.file_stem(), ".env");
+    assert_eq!(p.extension(), Some("local"));
+    p.set_file_stem(".config").unwrap();
+    assert_eq!(format!("{p:?}"), "dir.d/.config.local");
+
+    let mut p = PathInner::<LinTestOS>::new(".gitignore").unwrap();
+    p.set_extensions("bak");
+    assert_eq!(format!("{p:?}"), ".gitignore.bak");
+    p.set_extensions(Vec::<String>::new());
+    assert_eq!(format!("{p:?}"), ".gitignore");
+
+    let p = PathInner::<WinTestOS>::new("c:\\dir\\..hidden.txt").unwrap();
+    assert_eq!(p.file_stem(), "..hidden");
+    assert_eq!(p.extension(), Some("txt"));
+}
+
+#[test]
+fn test_full_extension() {
+    let p = PathInner::<LinTestOS>::new("/some/archive.tar.gz").unwrap();
+    assert_eq!(p.file_stem(), "archive");
+    assert_eq!(p.extension(), Some("gz"));
+    assert_eq!(p.full_extension(), Some("tar.gz"));
+    assert_eq!(p.extensions().collect::<Vec<_>>(), vec!["tar", "gz"]);
+
+    // dots in folder names are not extensions
+    let p = PathInner::<LinTestOS>::new("some.dir/file").unwrap();
+    assert_eq!(p.extensions().count(), 0);
+    assert_eq!(p.full_extension(), None);
+
+    let p = PathInner::<LinTestOS>::new("file.tar.").unwrap();
+    assert_eq!(p.extensions().collect::<Vec<_>>(), vec!["tar"]);
+}
+
+#[test]
+fn test_parent_rel() {
+    let p = PathInner::<LinTestOS>::new("dir1/dir2/file").unwrap();
+    let parent = p.parent().unwrap();
+    assert_eq!(format!("{parent:?}"), "dir1/dir2/");
+
+    let p = PathInner::<LinTestOS>::new("../../").unwrap();
+    let parent = p.parent().unwrap();
+    assert_eq!(format!("{parent:?}"), "../../../");
+
+    let p = PathInner::<WinTestOS>::new("..\\dir\\").unwrap();
+    let parent = p.parent().unwrap();
+    assert_eq!(&parent.path, "C:..\\");
+    let parent = parent.parent().unwrap();
+    assert_eq!(&parent.path, "C:..\\..\\");
+}
+
+#[test]
+fn test_up_levels() {
+    let p = PathInner::<LinTestOS>::new("../../dir/").unwrap();
+    assert_eq!(p.path, "../../dir/");
+    assert_eq!(p.up_levels(), 2);
+
+    let p = PathInner::<LinTestOS>::new("dir1/../../dir2").unwrap();
+    assert_eq!(p.path, "../dir2");
+    assert_eq!(p.up_levels(), 1);
+
+    let p = PathInner::<LinTestOS>::new("dir1/dir2/../file").unwrap();
+    assert_eq!(p.path, "dir1/file");
+    assert_eq!(p.up_levels(), 0);
+
+    let p = PathInner::<LinTestOS>::new("dir1/..").unwrap();
+    assert_eq!(p.path, "");
+}
+
+#[test]
+fn test_resolve() {
+    let base = PathInner::<LinTestOS>::new("/base/dir/").unwrap();
+
+    let p = PathInner::<LinTestOS>::new("../other/file.txt").unwrap();
+    assert_eq!(p.resolve(&base).unwrap().path, "/base/other/file.txt");
+
+    let p = PathInner::<LinTestOS>::new("../../").unwrap();
+    assert_eq!(p.resolve(&base).unwrap().path, "/");
+
+    let p = PathInner::<LinTestOS>::new("sub/").unwrap();
+    assert_eq!(p.resolve(&base).unwrap().path, "/base/dir/sub/");
+
+    let p = PathInner::<LinTestOS>::new("../../../etc").unwrap();
+    assert_eq!(
+        p.resolve(&base).unwrap_err().to_string(),
+        "path climbs above the root: ../../../etc (resolved against /base/dir/)"
+    );
+
+    let base = PathInner::<WinTestOS>::new("c:\\base\\dir\\").unwrap();
+    let p = PathInner::<WinTestOS>::new("..\\file.txt").unwrap();
+    assert_eq!(p.resolve(&base).unwrap().path, "C:\\base\\file.txt");
+}
+
+#[test]
+fn test_segment_edit() {
+    let p = PathInner::<LinTestOS>::new("/a/b/c.txt").unwrap();
+    assert_eq!(p.slice_segments(1..).unwrap().path, "b/c.txt/");
+    assert_eq!(p.slice_segments(..=1).unwrap().path, "a/b/");
+    assert_eq!(
+        p.slice_segments(1..4).unwrap_err().to_string(),
+        "segment range 1..4 is out of bounds for /a/b/c.txt with 3 segments"
+    );
+    assert!(p.slice_segments(1..1).is_err());
+
+    let mut e = p.clone();
+    e.replace_segment(1, "x").unwrap();
+    assert_eq!(e.path, "/a/x/c.txt");
+    e.insert_segment(3, "d.txt").unwrap();
+    assert_eq!(e.path, "/a/x/c.txt/d.txt");
+    e.remove_segment(0).unwrap();
+    assert_eq!(e.path, "/x/c.txt/d.txt");
+    assert_eq!(
+        e.replace_segment(0, "y/z").unwrap_err().to_string(),
+        "A file name cannot contain slashes: y/z"
+    );
+    assert_eq!(
+        e.insert_segment(0, "..").unwrap_err().to_string(),
+        "'..' is not allowed as a segment"
+    );
+    assert!(e.insert_segment(0, ".").is_err());
+    assert!(e.replace_segment(0, ".").is_err());
+    assert_eq!(
+        e.remove_segment(3).unwrap_err().to_string(),
+        "segment index 3 is out of bounds for /x/c.txt/d.txt with 3 segments"
+    );
+
+    let (folder, rest) = p.split_at_segment(1).unwrap();
+    assert_eq!((folder.as_str(), rest.as_str()), ("/a/", "b/c.txt"));
+    let (folder, _) = p.split_at_segment(0).unwrap();
+    assert_eq!(folder.path, "/");
+
+    let mut dir = PathInner::<LinTestOS>::new("/a/").unwrap();
+    dir.remove_segment(0).unwrap();
+    assert_eq!(dir.path, "/");
+
+    let mut rel = PathInner::<LinTestOS>::new("a/b/").unwrap();
+    rel.insert_segment(0, "x").unwrap();
+    assert_eq!(rel.path, "x/a/b/");
+    assert!(rel.split_at_segment(0).is_err());
+
+    let mut up = PathInner::<LinTestOS>::new("../../a/").unwrap();
+    assert_eq!(
+        up.insert_segment(0, "x").unwrap_err().to_string(),
+        "cannot put 'x' in front of the leading '..' of ../../a/"
+    );
+    assert!(up.insert_segment(1, "x").is_err());
+    assert!(up.replace_segment(0, "x").is_err());
+    up.replace_segment(1, "x").unwrap();
+    assert_eq!(up.path, "../x/a/");
+    up.insert_segment(1, "y").unwrap();
+    assert_eq!(up.path, "../y/x/a/");
+    assert_eq!(up, PathInner::new_expanded(up.as_str()).unwrap());
+
+    let mut file = PathInner::<LinTestOS>::new("f.txt").unwrap();
+    assert!(file.remove_segment(0).is_err());
+
+    let mut win = PathInner::<WinTestOS>::new(r"C:\a\b\").unwrap();
+    win.replace_segment(0, "x").unwrap();
+    assert_eq!(win.path, r"C:\x\b\");
+    let (folder, rest) = win.split_at_segment(1).unwrap();
+    assert_eq!(folder.path, r"C:\x\");
+    assert_eq!(rest.path, r"C:b\");
+}
+
+#[test]
+fn test_joined() {
+    let lin = |a: &str, b: &str| {
+        let a = PathInner::<LinTestOS>::new(a).unwrap();
+        let b = PathInner::<LinTestOS>::new(b).unwrap();
+        b.resolve(&a)
+            .map(|p| p.path.to_string())
+            .map_err(|e| e.to_string())
+    };
+    assert_eq!(lin("/a/", "b/c.txt").unwrap(), "/a/b/c.txt");
+    assert_eq!(lin("/a/b/", "../c/").unwrap(), "/a/c/");
+    assert_eq!(lin("/a/", "../c").unwrap(), "/c");
+    assert_eq!(
+        lin("/a/", "../../c").unwrap_err(),
+        "path climbs above the root: ../../c (resolved against /a/)"
+    );
+    assert_eq!(lin("a/", "../../c/").unwrap(), "../c/");
+    assert_eq!(lin("../", "../c").unwrap(), "../../c");
+
+    let empty = PathInner::<LinTestOS>::new("a/").unwrap();
+    let empty = PathInner::<LinTestOS>::new("../")
+        .unwrap()
+        .resolve(&empty)
+        .unwrap();
+    assert_eq!(empty.path, "");
+    assert!(empty.is_folder() && empty.segments().next().is_none());
+    assert_eq!(lin("a/b/", "../../").unwrap(), "");
+
+    let win = |a: &str, b: &str| {
+        let a = PathInner::<WinTestOS>::new(a).unwrap();
+        let b = PathInner::<WinTestOS>::new(b).unwrap();
+        b.resolve(&a).unwrap().path.to_string()
+    };
+    assert_eq!(win(r"C:\a\", r"b\c.txt"), r"C:\a\b\c.txt");
+    assert_eq!(win(r"a\", r"..\b\"), r"C:b\");
+    assert_eq!(win(r"a\", r"..\"), "C:");
+}
+
+#[test]
+fn test_ord() {
+    let a = PathInner::<WinTestOS>::new(r"C:\a\b").unwrap();
+    let b = PathInner::<WinTestOS>::new(r"C:\a-b\").unwrap();
+    let d = PathInner::<WinTestOS>::new(r"D:\a\").unwrap();
+    assert!(*a < *b);
+    assert!(*b < *d);
+
+    let file = PathInner::<LinTestOS>::new("/a/b").unwrap();
+    let folder = PathInner::<LinTestOS>::new("/a/b/").unwrap();
+    assert!(*file < *folder);
+}
+
+#[test]
+fn test_case_policy() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let upper = PathInner::<WinTestOS>::new(r"C:\Users\Me\").unwrap();
+    let lower = PathInner::<WinTestOS>::new(r"c:\users\me\").unwrap();
+    assert_eq!(upper, lower);
+    assert_eq!(hash(&*upper), hash(&*lower));
+    assert_eq!(upper.cmp(&lower), std::cmp::Ordering::Equal);
+
+    let upper = PathInner::<LinTestOS>::new("/Users/Me/").unwrap();
+    let lower = PathInner::<LinTestOS>::new("/users/me/").unwrap();
+    assert_ne!(upper, lower);
+    assert!(upper.eq_case(&lower, false));
+    assert_eq!(upper.cmp_case(&lower, false), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_natural_order() {
+    use super::natural::cmp_natural_str;
+    use std::cmp::Ordering::*;
+
+    assert_eq!(cmp_natural_str("file2.png", "file10.png", true), Less);
+    assert_eq!(cmp_natural_str("file010", "file10", true), Equal);
+    assert_eq!(
+        cmp_natural_str(
+            "a99999999999999999999999",
+            "a100000000000000000000000",
+            true
+        ),
+        Less
+    );
+    assert_eq!(cmp_natural_str("B", "a", true), Less);
+    assert_eq!(cmp_natural_str("B", "a", false), Greater);
+    assert_eq!(cmp_natural_str("v1.2", "v1.10", true), Less);
+
+    let file = PathInner::<LinTestOS>::new("/a/z.txt").unwrap();
+    let folder = PathInner::<LinTestOS>::new("/a/b/c").unwrap();
+    assert_eq!(file.cmp_natural(&folder, true, false), Greater);
+    assert_eq!(file.cmp_natural(&folder, true, true), Greater);
+    let file = PathInner::<LinTestOS>::new("/a/a.txt").unwrap();
+    assert_eq!(file.cmp_natural(&folder, true, false), Less);
+    assert_eq!(file.cmp_natural(&folder, true, true), Greater);
+
+    let padded = PathInner::<LinTestOS>::new("/a/file010").unwrap();
+    let plain = PathInner::<LinTestOS>::new("/a/file10").unwrap();
+    assert_eq!(
+        padded.cmp_natural(&plain, true, false),
+        padded.cmp_case(&plain, true)
+    );
+
+    let upper = PathInner::<WinTestOS>::new(r"C:\Dir\file2").unwrap();
+    let lower = PathInner::<WinTestOS>::new(r"c:\dir\file10").unwrap();
+    assert_eq!(upper.cmp_natural(&lower, false, false), Less);
+}
+
+/// All orderings of the items, to check that sorting doesn't depend on the input order.
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    let mut all = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut perm in permutations(&rest) {
+            perm.insert(0, first.clone());
+            all.push(perm);
+        }
+    }
+    all
+}
+
+#[test]
+fn test_natural_order_is_total() {
+    use std::cmp::Ordering::*;
+
+    let paths = [
+        "a", "a/x", "a0/", "a/", "a/x/", "A", "a2/", "a10", "a02", "b/y", "b", "x/a",
+    ]
+    .map(|p| PathInner::<LinTestOS>::new_expanded(p).unwrap());
+
+    for (case_sensitive, folders_first) in [(true, false), (true, true), (false, true)] {
+        let cmp = |a: &PathInner<LinTestOS>, b: &PathInner<LinTestOS>| {
+            a.cmp_natural(b, case_sensitive, folders_first)
+        };
+        for a in &paths {
+            assert_eq!(cmp(a, a), Equal);
+            for b in &paths {
+                assert_eq!(cmp(a, b), cmp(b, a).reverse(), "{a} {b}");
+                for c in &paths {
+                    if cmp(a, b) != Greater && cmp(b, c) != Greater {
+                        assert_ne!(cmp(a, c), Greater, "{a} <= {b} <= {c}");
+                    }
+                }
+            }
+        }
+
+        let sorted = |mut items: Vec<PathInner<LinTestOS>>| {
+            items.sort_by(cmp);
+            items
+        };
+        let expected = sorted(paths[..6].to_vec());
+        for perm in permutations(&paths[..6]) {
+            let perm = sorted(perm);
+            assert!(perm.iter().zip(&expected).all(|(a, b)| cmp(a, b) == Equal));
+        }
+    }
+
+    let [file, nested, folder] =
+        ["a", "a/x", "a0/"].map(|p| PathInner::<LinTestOS>::new_expanded(p).unwrap());
+    assert_eq!(nested.cmp_natural(&folder, true, true), Less);
+    assert_eq!(folder.cmp_natural(&file, true, true), Less);
+    assert_eq!(nested.cmp_natural(&file, true, true), Less);
+}
+
+#[test]
+fn test_eq_str() {
+    let lin = |p: &str, s: &str| PathInner::<LinTestOS>::new(p).unwrap().eq_str(s, true);
+    assert_eq!(lin("/a/b/", "/a/b/"), Some(true));
+    assert_eq!(lin("/a/b/", "//a/./x/../b/"), Some(true));
+    assert_eq!(lin("/a/b/", "/a/b"), Some(false));
+    assert_eq!(lin("/a/b", "/a/B"), Some(false));
+    assert_eq!(lin("/a/b", "c:/a/b"), Some(true));
+    assert_eq!(lin("/", "/"), Some(true));
+    assert_eq!(lin("/", "/a/.."), Some(true));
+    assert_eq!(lin("/b", "/../../b"), Some(true));
+    assert_eq!(lin("/a/b", "a/b"), Some(false));
+    assert_eq!(lin("../a", "../a"), Some(true));
+    assert_eq!(lin("../a", "x/../../a"), Some(true));
+    assert_eq!(lin("../a", "a"), Some(false));
+    assert_eq!(lin("a/", "a/x/.."), Some(true));
+    assert_eq!(lin("a/b", "a"), Some(false));
+    assert_eq!(lin("a", "a/b"), Some(false));
+    assert_eq!(lin("/home/test/a", "~/a"), None);
+    assert_eq!(lin("/var/test/a", "./a"), None);
+    assert_eq!(lin("/a/b", "/$A/b"), None);
+
+    let win = |p: &str, s: &str| PathInner::<WinTestOS>::new(p).unwrap().eq_str(s, false);
+    assert_eq!(win("c:\\a\\b\\", "C:/A/B/"), Some(true));
+    assert_eq!(win("\\a\\b", "\\a\\b"), Some(true));
+    assert_eq!(win("d:\\a\\b", "\\a\\b"), Some(false));
+    assert_eq!(win("d:\\a\\b", "c:\\a\\b"), Some(false));
+    assert_eq!(win("a\\b", "a/b"), Some(true));
+    assert_eq!(win("a\\b", "\\a\\b"), Some(false));
+    assert_eq!(win("..\\a", "x\\..\\..\\a"), Some(true));
+}
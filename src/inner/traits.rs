@@ -7,15 +7,21 @@ use serde::Serialize;
 
 use crate::os::OsGroup;
 
-use super::PathInner;
+use super::{PathInner, PathStr};
 
-impl<OS: OsGroup> AsRef<Path> for PathInner<OS> {
+impl<OS: OsGroup> AsRef<Path> for PathStr<OS> {
     fn as_ref(&self) -> &Path {
         Path::new(&self.path)
     }
 }
 
-impl<OS: OsGroup> Display for PathInner<OS> {
+impl<OS: OsGroup> AsRef<Path> for PathInner<OS> {
+    fn as_ref(&self) -> &Path {
+        Path::new(&*self.path)
+    }
+}
+
+impl<OS: OsGroup> Display for PathStr<OS> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let (chr, path) = self.as_contracted(!f.alternate());
 
@@ -26,18 +32,30 @@ impl<OS: OsGroup> Display for PathInner<OS> {
     }
 }
 
-impl<OS: OsGroup> Debug for PathInner<OS> {
+impl<OS: OsGroup> Display for PathInner<OS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<OS: OsGroup> Debug for PathStr<OS> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         OS::debug_fmt(&self.path, f)
     }
 }
 
+impl<OS: OsGroup> Debug for PathInner<OS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
 pub trait TryExist<T>: Sized {
     /// Performs the conversion.
     fn try_exist(value: T) -> anyhow::Result<Self>;
 }
 
-impl<OS: OsGroup> Serialize for PathInner<OS> {
+impl<OS: OsGroup> Serialize for PathStr<OS> {
     fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -45,3 +63,60 @@ impl<OS: OsGroup> Serialize for PathInner<OS> {
         ser.serialize_str(&format!("{self:?}"))
     }
 }
+
+impl<OS: OsGroup> Serialize for PathInner<OS> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (**self).serialize(ser)
+    }
+}
+
+pub trait TryFromFs<T>: Sized {
+    /// Performs the conversion. When the path doesn't end with a slash,
+    /// the file system decides if it is a folder or a file.
+    fn try_from_fs(value: T) -> anyhow::Result<Self>;
+}
+
+/// Conversions for paths that are written to rather than read from.
+pub trait TryOutput<T>: Sized {
+    /// Performs the conversion and checks that the path can be created,
+    /// i.e. the folder it goes in exists and is writable.
+    fn try_creatable(value: T) -> anyhow::Result<Self>;
+
+    /// Performs the conversion and checks that nothing exists at the path yet.
+    fn try_fresh(value: T) -> anyhow::Result<Self>;
+
+    /// Performs the conversion and creates the folder, or for a file the folder
+    /// it goes in, including any missing parent folders.
+    fn try_ensure_dir(value: T) -> anyhow::Result<Self>;
+}
+
+/// Joins a relative path onto a folder, where the type of the result follows
+/// from the types joined, ex: an absolute folder joined with a relative file
+/// is an absolute file. Fails if the relative path climbs above the root of an
+/// absolute folder, like [resolve](crate::IsRelative::resolve). Also available
+/// as the `/` operator, which panics instead.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{AbsoluteFilePath, AbsoluteFolderPath, Join, PathLike, RelativeFilePath};
+///
+/// let dir = AbsoluteFolderPath::try_from("/project/")?;
+/// let file = RelativeFilePath::try_from("src/main.rs")?;
+/// let joined: AbsoluteFilePath = dir.joining(&file)?;
+/// # #[cfg(not(windows))]
+/// assert_eq!(joined.as_str(), "/project/src/main.rs");
+/// assert_eq!(&dir / &file, joined);
+///
+/// let up = RelativeFilePath::try_from("../../etc/passwd")?;
+/// assert!(dir.joining(&up).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub trait Join<Rhs> {
+    type Output;
+
+    fn joining(&self, rhs: Rhs) -> anyhow::Result<Self::Output>;
+}
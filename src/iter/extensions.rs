@@ -5,22 +5,20 @@ pub struct Extensions<'a> {
 }
 
 impl<'a> Extensions<'a> {
-    pub(crate) fn new(file: &'a str) -> Self {
-        let parts = if let Some(idx) = index_after_first_dot(file) {
-            let mut lengths = Vec::new();
-            file[idx..]
-                .split_inclusive('.')
-                .filter(|s| !s.is_empty())
-                .map(|s| s.len())
-                .fold(0, |acc, val| {
-                    lengths.push(acc + val);
-                    acc + val
-                });
+    /// Takes the full extension of a file, i.e. everything after the stem
+    /// without the separating dot, ex: `tar.gz`.
+    pub(crate) fn new(full_extension: &'a str) -> Self {
+        let mut lengths = Vec::new();
+        full_extension
+            .split_inclusive('.')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.len())
+            .fold(0, |acc, val| {
+                lengths.push(acc + val);
+                acc + val
+            });
 
-            Segments::new_with_lengths(&file[idx..], lengths)
-        } else {
-            Segments::new_with_lengths(file, vec![])
-        };
+        let parts = Segments::new_with_lengths(full_extension, lengths);
         Self { parts }
     }
 }
@@ -32,16 +30,3 @@ impl<'a> Iterator for Extensions<'a> {
         self.parts.next()
     }
 }
-
-fn index_after_first_dot(s: &str) -> Option<usize> {
-    if let Some(idx) = s.find('.') {
-        let idx = idx + 1;
-        if idx >= s.len() {
-            None
-        } else {
-            Some(idx)
-        }
-    } else {
-        None
-    }
-}
@@ -1,16 +1,44 @@
-use std::iter::Peekable;
-use std::str::Split;
+use std::vec::IntoIter;
 
 use crate::SLASH;
 
+/// Iterates over the lexically normalised segments of a path, i.e. empty
+/// and `.` segments are dropped and a `..` cancels out the segment before it.
+///
+/// A `..` that can't be cancelled out is kept when the path is relative, so that
+/// `../../dir` stays as is, and dropped when the path is absolute since there's
+/// nothing above the root.
 pub struct InnerSegmentIter<'a> {
-    iter: Peekable<Split<'a, [char; 2]>>,
+    iter: IntoIter<&'a str>,
+    ends_with_folder: bool,
 }
 
 impl<'a> InnerSegmentIter<'a> {
     pub(crate) fn new(path: &'a str) -> Self {
+        let is_absolute = path.starts_with(SLASH);
+        let mut segments: Vec<&str> = Vec::new();
+        let mut last = "";
+
+        for (i, segment) in path.split(SLASH).enumerate() {
+            last = segment;
+            match segment {
+                "" => {}
+                // a leading dot is kept as it refers to the current dir
+                "." if i != 0 => {}
+                ".." => match segments.last() {
+                    Some(&prev) if prev != "." && prev != ".." => {
+                        segments.pop();
+                    }
+                    None if is_absolute => {}
+                    _ => segments.push(segment),
+                },
+                _ => segments.push(segment),
+            }
+        }
+        let ends_with_folder = !segments.is_empty() && matches!(last, "" | "." | "..");
         Self {
-            iter: path.split(SLASH).peekable(),
+            iter: segments.into_iter(),
+            ends_with_folder,
         }
     }
 }
@@ -19,20 +47,12 @@ impl<'a> Iterator for InnerSegmentIter<'a> {
     type Item = (&'a str, bool);
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(next) = self.iter.next() {
-            if self.iter.next_if_eq(&"..").is_some() {
-                //skip if next is .. (which is skipped as well)
-            } else if !next.is_empty() {
-                let has_more = self.iter.peek().is_some();
-                return Some((next, has_more));
-            }
-        }
-        None
+        let next = self.iter.next()?;
+        let has_more = self.iter.len() > 0 || self.ends_with_folder;
+        Some((next, has_more))
     }
 }
 
-impl<'a> InnerSegmentIter<'a> {}
-
 #[test]
 fn test_path_iter() {
     assert_eq!(segs("./dir1//dir2/"), vec![".", "dir1", "dir2"]);
@@ -47,6 +67,15 @@ fn test_path_iter() {
     assert_eq!(segs("~/"), vec!["~"]);
 }
 
+#[test]
+fn test_leading_parent_dirs() {
+    assert_eq!(segs("../../dir"), vec!["..", "..", "dir"]);
+    assert_eq!(segs("dir1/../../dir2"), vec!["..", "dir2"]);
+    assert_eq!(segs("dir1/./dir2/.."), vec!["dir1"]);
+    assert_eq!(segs("/../dir"), vec!["dir"]);
+    assert_eq!(segs("/dir/../../"), Vec::<&str>::new());
+}
+
 #[cfg(test)]
 fn segs(path: &str) -> Vec<String> {
     InnerSegmentIter::new(path)
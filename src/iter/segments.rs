@@ -5,6 +5,9 @@ pub struct Segments<'a> {
     path: &'a str,
     lengths: Vec<usize>,
     pos: isize,
+    /// false until next or next_back has been called, so that
+    /// next_back can start from the end
+    started: bool,
 }
 
 impl<'a> Segments<'a> {
@@ -22,7 +25,6 @@ impl<'a> Segments<'a> {
                 lengths.push(acc + val);
                 acc + val
             });
-        println!("lengths: {lengths:?}");
         Self::new_with_lengths(path, lengths)
     }
 
@@ -31,6 +33,7 @@ impl<'a> Segments<'a> {
             path,
             lengths,
             pos: -1,
+            started: false,
         }
     }
 
@@ -50,6 +53,7 @@ impl<'a> Iterator for Segments<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.started = true;
         let len = self.lengths.len();
         let pos = if len == 0 {
             return None;
@@ -81,9 +85,10 @@ impl<'a> ExactSizeIterator for Segments<'a> {}
 impl<'a> DoubleEndedIterator for Segments<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let len = self.lengths.len();
+        let started = std::mem::replace(&mut self.started, true);
         let pos = if len == 0 {
             return None;
-        } else if self.pos >= len as isize {
+        } else if self.pos >= len as isize || !started {
             len - 1
         } else if self.pos - 1 < 0 {
             self.pos = -1;
@@ -118,6 +123,15 @@ fn test_path_iter() {
     assert_eq!(iter.next_back(), None);
 }
 
+#[test]
+fn test_path_iter_rev() {
+    let path = PathInner::<LinTestOS>::new("/var/some/paths/").unwrap();
+
+    let segs = path.segments().rev().collect::<Vec<_>>();
+    assert_eq!(segs, vec!["paths", "some", "var"]);
+    assert_eq!(path.segments().next_back(), Some("paths"));
+}
+
 #[test]
 fn test_abs_path_iter() {
     let path = PathInner::<LinTestOS>::new("/var/some/paths").unwrap();
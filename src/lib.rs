@@ -16,6 +16,31 @@
 //! dir2 = "C:\\anotherdir\\%ANOTHER_ENV%"
 //! ```
 //!
+//! The absolute types come with serde modules for use with `#[serde(with = "...")]`
+//! that check the file system when deserializing:
+//!
+//! | Module        | Use          | Checks
+//! | ---           | ---          | ---
+//! | `exist`       | input        | the path exists and is of the right kind
+//! | `creatable`   | output       | the folder it goes in exists and is writable
+//! | `fresh`       | output       | nothing exists at the path yet
+//! | `ensure_dir`  | scratch      | creates the folder, or for files the folder it goes in
+//!
+//! ```rust
+//! # use x_path::{AbsoluteFilePath, AbsoluteFolderPath};
+//! #[derive(serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "x_path::absolute_file_path::exist")]
+//!     input: AbsoluteFilePath,
+//!     #[serde(with = "x_path::absolute_file_path::creatable")]
+//!     report: AbsoluteFilePath,
+//!     #[serde(with = "x_path::absolute_folder_path::ensure_dir")]
+//!     cache: AbsoluteFolderPath,
+//! }
+//! ```
+//!
+//! The same checks are available without serde through [TryExist] and [TryOutput].
+//!
 //! ## Clear expectations
 //!
 //! Use one of the below to communicate what your function or API expects.
@@ -46,6 +71,11 @@
 //! A path that starts with any of `<drive>:\`, `\`, `/`, `.`, `~` is absolute.
 //! A path that ends with `/` or `\` is a folder.
 //!
+//! Users tend to leave out the trailing slash of folders, for instance in command line
+//! arguments, so the [TryFromFs] constructors of the absolute types ask the file system
+//! when the slash is missing. An existing folder gets the slash added, whereas an
+//! existing file is rejected when a folder is expected and vice versa.
+//!
 //! ```rust
 //! # use x_path::AbsoluteFolderPath;
 //! #
@@ -95,6 +125,9 @@
 //! - Iterate through all the path segments as `&str`ings with `path.segments()`.
 //! - Many convenient functions: see the doc for each path type.
 //!
+//! Enable the feature `arc-str` to store the paths as `Arc<str>`, which makes `.clone()` O(1)
+//! but each edit a copy. Compare both with `cargo bench --bench clone [--features arc-str]`.
+//!
 //! # Design goals
 //!
 //! - Make rust's typical _"if it compiles it works"_ experience work for cross-platform path handling as well.
@@ -192,6 +225,8 @@
 //! | `dir/./dir`              | nix: `dir/dir/`<br>win: `C:dir\dir\`       |                    |                                          | Dots inside of a path are ignored
 //! | `dir/..`                 |                                            |                    |                                          | Empty path
 //! | `dir1/dir2/..`           | nix: `dir1/`<br>win: `C:dir1\`             |                    |                                          |
+//! | `dir1/../../dir2`        | nix: `../dir2`<br>win: `C:..\dir2`         |                    |                                          | Leading `..` are kept in relative paths, see `.up_levels()` and `.resolve()`
+//! | `/../dir`                | nix: `/dir`<br>win: `C:\dir`               |                    |                                          | There is nothing above the root
 //! | `${MYDIR}`,<br>`%MYDIR%` | nix: `dir/`<br>win: `C:dir\`               | var("MYDIR")       | `dir`                                    | See [Environment variables](#environment-variables)
 //!
 //! Legend:
@@ -239,6 +274,30 @@
 //!
 //! ## Conversions between X-Path types
 //!
+//! All nine types are aliases of one generic [TypedPath], ex: [AbsoluteFilePath] is `TypedPath<Abs, File>`,
+//! with the [marker] types `Abs`, `Rel` and `AnyBase` for where the path starts and `File`, `Folder` and
+//! `AnyKind` for what it points to. `.widen()` converts to a wider type without checks, `.narrow()`
+//! converts to any type and fails if the path isn't of that type and `.try_narrow()` gives the path back
+//! on failure.
+//!
+//! ```
+//! # fn main() -> anyhow::Result<()> {
+//! use x_path::{AnyPath, RelativeFolderPath};
+//!
+//! let any = AnyPath::try_from("dir/sub/")?;
+//! let folder: RelativeFolderPath = any.narrow()?;
+//! let any: AnyPath = folder.widen();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Borrowed paths
+//!
+//! Each type has a borrowed form with `Ref` appended, ex: [AbsoluteFolderPathRef], that relates to it
+//! like [Path](std::path::Path) to [PathBuf](std::path::PathBuf), see [TypedPathRef]. A path derefs
+//! to its borrowed form, so functions taking a `&AbsoluteFolderPathRef` accept both, and
+//! `.dropping_file()`, `.parent()` and `.leading(count)` return views into the path instead of copies.
+//!
 //! ### Making abstract types concrete
 //!
 //! These types are typically used for validation purposes when read from file, and
@@ -274,24 +333,75 @@
 //!
 //! | To → <br> From ↓    | [AnyFolderPath]    | [AnyFilePath]             | [AnyPath]
 //! | ---                 | ---                | ---                       | ---         
-//! | **[AnyFolderPath]** | -                  | `.with_file(AnyFilePath)` | `.into()`
+//! | **[AnyFolderPath]** | -                  | `.with_file(RelativeFilePath)` | `.into()`
 //! | **[AnyFilePath]**   | `.dropping_file()` | -                         | `.into()`
 //! | **[AnyPath]**       | `.try_into()`      | `.try_into()`             | -           
 //!
-//! Functions provided per type.
+//! Functions provided per type. Most of them come from the traits [PathLike], [FileLike], [FolderLike],
+//! [IsAbsolute] and [IsRelative], which generic code can use to accept several path types.
+//! The paths have the same functions without the traits in scope, generic code imports them all
+//! with `use x_path::prelude::*`.
 //!
 //! - All:
 //!     - `.as_str`, gives access to &str funcs incl. `.chars`, `.starts_with`, `.ends_with`
+//!       but note that these match partial segment names.
+//!     - `.ends_with` compares whole segments with a relative path, `.depth` is the number of segments.
+//!     - `==` and `<` work between the types that can hold the same path, ex: [AbsoluteFolderPath] and
+//!       [AnyFolderPath] but not [AbsolutePath] and [RelativePath], and with a `&str` segment by segment,
+//!       where a string starting with `~` or `.` is expanded with the home or current folder first.
+//!     - `==`, `Hash` and `Ord` ignore case on Windows, like its file systems. `.eq_ignore_case` and
+//!       the [CaseInsensitive] wrapper do the same elsewhere, ex: for the default file systems of macOS.
+//!     - [natural_cmp] sorts listings such as those of `.read_dir` like people do, i.e. `file2.png`
+//!       before `file10.png`, [natural_cmp_folders_first] puts folders first and [NaturalOrd] wraps a
+//!       path to order it naturally.
+//!     - `.slice`, `.split_at` and `.replace_segment`, `.insert_segment`, `.remove_segment` (and the
+//!       `-ing` variants that return a copy) edit segments by index, keeping the file/folder kind.
 //!     - `.as_path`, gives access to Path funcs incl. `.metadata`, `is_symlink`
 //!     - `.segments`, `.with_segments`, `.set_segments`. For segments starting from the end use `.segments` + `.rev`.
 //!     - `.exists`
+//! - Absolute:
+//!     - `.canonicalize` resolves symlinks using the file system and keeps the file/folder type,
+//!       see [KindChanged]. Paths are normalised when created, so the `..` in `/a/link/../b` is
+//!       removed before the link could be followed, `AbsolutePath::canonicalize_str` resolves the
+//!       string as written instead.
+//!     - `.is_same_file` checks if two paths point to the same file or folder on disk.
+//!     - `.ancestors` iterates over the containing folders up to the root, `.starts_with` compares whole
+//!       segments with a folder.
+//!     - Folders also have `.is_ancestor_of` and `AbsoluteFolderPath::common_ancestor`.
+//! - Absolute file, with errors that include the path:
+//!     - `.read`, `.read_to_string`, `.write`, `.remove`
+//!     - `.copy_to`, `.move_to` into a folder, `.rename_to` within its folder, all returning the new path.
+//!     - `.write_atomic`, `.write_atomic_with_backup` and the streaming `.atomic_writer` never leave a
+//!       partially written file behind, see [AtomicWriter].
+//! - Absolute folder, with errors that include the path:
+//!     - `.create_all`, `.remove_all`, `.read_dir`
+//!     - `.copy_tree_to` copies the folder with its content into another folder and returns the copy.
+//!     - `.find_ancestor_with` finds the nearest folder upwards containing a marker such as `Cargo.toml`
+//!       or `.git/`, with the search limited by [StopAt]. [Cascade] collects all config files upwards.
+//! - Known folders: `AbsoluteFolderPath::config_dir`, `cache_dir`, `data_dir`, `state_dir` and `runtime_dir`
+//!   follow the XDG Base Directory spec on unix and use the known folders on windows,
+//!   `user_dirs` returns the documents and downloads folders, see [UserDirs]. [KnownFolders] does
+//!   the same with another environment, ex: in tests.
+//! - Collections: [PathMap] and [PathSet] store paths in a trie of their segments, iterate in path order and
+//!   answer `.longest_prefix`, i.e. the most specific folder containing a file, `.descendants` and
+//!   `.remove_subtree`. [PathArena] interns many paths as shared `(parent, segment)` nodes behind `Copy`
+//!   handles that resolve back to typed paths.
+//! - Temporary: [TempFolder] and [TempFile] are removed when dropped unless `.keep` is called,
+//!   `new_pair` returns the guard together with the typed path.
+//! - Snapshots: [Redactions] rewrites the home, current, temp and custom folders in text to
+//!   placeholders such as `[HOME]/` so that snapshots are the same on every machine. With the
+//!   feature `redact-json`, `.redact_value` does it for every string of a serialisable value.
+//! - Fixtures: the [tree!] macro describes files and folders as a [Tree] that can be created in a folder
+//!   and compared with one using [assert_tree_eq], which lists missing, unexpected and changed entries.
 //! - Folder:
 //!     - `.push`, `.pushing` pushes one or more path segments.
 //!     - `.pop`, `.popping` pops the last path segment.
-//!     - `.join`, `.joining` appends a relative dir.
+//!     - `.join` appends a relative folder, `.joining` and the `/` operator join a relative folder, file
+//!       or path with a result of the matching type, see [Join].
 //! - File:
 //!     - `.file_name`, `.with_file_name`, `.set_file_name`, `.file_stem`, `.with_file_stem`, `.set_file_stem`
-//!     - `.extensions`: iterator over extensions
+//!     - `.extensions`: iterator over extensions, `.extension` the last one and `.full_extension` all of them, ex: `tar.gz`
+//!     - leading dots are part of the stem, i.e. `.env.local` has the stem `.env` and the extension `local`
 //!     - `.set_extensions`, `.with_extensions`: set extensions from any IntoIter&ltstr&gt
 //!
 //! # References
@@ -300,18 +410,36 @@
 //! - [Naming Files, Paths, and Namespaces](https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file)
 //! - [Wikipedia: Filenames - Comparison of filename limitations](https://en.wikipedia.org/wiki/Filename#Comparison_of_filename_limitations)
 
+mod error;
 mod ext;
+mod fs;
 mod inner;
 mod iter;
 mod macros;
+mod map;
 mod os;
 mod path;
+mod redact;
 
 const SLASH: [char; 2] = ['/', '\\'];
 
-pub use inner::{StrValues, TryExist};
+pub use error::KindChanged;
+pub use fs::{
+    assert_tree_eq, AtomicWriter, Cascade, StopAt, TempFile, TempFolder, Tree, TreeDiff, TreeEntry,
+};
+pub use inner::{Join, StrValues, TryExist, TryFromFs, TryOutput};
 pub use iter::*;
+pub use map::{ArenaPath, PathArena, PathMap, PathMapIter, PathSet, PathSetIter};
 pub use path::*;
+pub use redact::{redact, Redactions};
+
+/// The traits that provide the functions of the path types.
+pub mod prelude {
+    pub use crate::{
+        FileLike, FolderLike, IsAbsolute, IsRelative, Join, PathLike, TryExist, TryFromFs,
+        TryOutput,
+    };
+}
 
 #[cfg(test)]
 #[test]
@@ -1,7 +1,5 @@
-mod all_dirs;
-mod all_files;
-mod all_paths;
 mod serde_impl;
+mod tree;
 mod try_exist;
-mod try_from;
-mod with_file;
+mod try_from_fs;
+mod try_output;
@@ -1,4 +1,3 @@
-///
 #[macro_export]
 macro_rules! serde_exist {
     ($struct:ident) => {
@@ -46,3 +45,69 @@ macro_rules! serde_expanded {
         }
     };
 }
+
+/// Modules for output paths:
+/// - `creatable`: the folder it goes in exists and is writable.
+/// - `fresh`: nothing exists at the path yet.
+/// - `ensure_dir`: the folder, or for files the folder it goes in, is created.
+#[macro_export]
+macro_rules! serde_output {
+    ($struct:ident) => {
+        pub mod creatable {
+            use serde::{de::Error, Deserialize, Deserializer, Serializer};
+            use $crate::TryOutput;
+
+            pub fn serialize<S: Serializer>(
+                path: &super::$struct,
+                ser: S,
+            ) -> Result<S::Ok, S::Error> {
+                ser.serialize_str(&format!("{:?}", path.0))
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                des: D,
+            ) -> Result<super::$struct, D::Error> {
+                let s = String::deserialize(des).map_err(Error::custom)?;
+                super::$struct::try_creatable(s).map_err(Error::custom)
+            }
+        }
+
+        pub mod fresh {
+            use serde::{de::Error, Deserialize, Deserializer, Serializer};
+            use $crate::TryOutput;
+
+            pub fn serialize<S: Serializer>(
+                path: &super::$struct,
+                ser: S,
+            ) -> Result<S::Ok, S::Error> {
+                ser.serialize_str(&format!("{:?}", path.0))
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                des: D,
+            ) -> Result<super::$struct, D::Error> {
+                let s = String::deserialize(des).map_err(Error::custom)?;
+                super::$struct::try_fresh(s).map_err(Error::custom)
+            }
+        }
+
+        pub mod ensure_dir {
+            use serde::{de::Error, Deserialize, Deserializer, Serializer};
+            use $crate::TryOutput;
+
+            pub fn serialize<S: Serializer>(
+                path: &super::$struct,
+                ser: S,
+            ) -> Result<S::Ok, S::Error> {
+                ser.serialize_str(&format!("{:?}", path.0))
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                des: D,
+            ) -> Result<super::$struct, D::Error> {
+                let s = String::deserialize(des).map_err(Error::custom)?;
+                super::$struct::try_ensure_dir(s).map_err(Error::custom)
+            }
+        }
+    };
+}
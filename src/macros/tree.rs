@@ -0,0 +1,27 @@
+/// Creates a [Tree](crate::Tree) where a name followed by a string is a file with
+/// that content and a name followed by `{ ... }` is a folder.
+///
+/// ```
+/// let spec = x_path::tree! {
+///     "src/" => {
+///         "main.rs" => "fn main() {}",
+///         "empty/" => {},
+///     },
+///     "README.md" => "",
+/// };
+/// assert_eq!(spec.entries().count(), 2);
+/// ```
+#[macro_export]
+macro_rules! tree {
+    (@entry $tree:ident, $name:literal, { $($inner:tt)* }) => {
+        $tree.folder($name, $crate::tree! { $($inner)* })
+    };
+    (@entry $tree:ident, $name:literal, $content:expr) => {
+        $tree.file($name, $content)
+    };
+    ($($name:literal => $entry:tt),* $(,)?) => {{
+        let tree = $crate::Tree::new();
+        $(let tree = $crate::tree!(@entry tree, $name, $entry);)*
+        tree
+    }};
+}
@@ -4,7 +4,7 @@ macro_rules! try_exist {
     ($struct:ident) => {
         impl $crate::TryExist<String> for $struct {
             fn try_exist(value: String) -> anyhow::Result<Self> {
-                let me = Self(PathInner::new(&value)?);
+                let me = Self::from_inner(PathInner::new(&value)?);
                 me.validate_fs()?;
                 Ok(me)
             }
@@ -12,7 +12,7 @@ macro_rules! try_exist {
 
         impl $crate::TryExist<&str> for $struct {
             fn try_exist(value: &str) -> anyhow::Result<Self> {
-                let me = Self(PathInner::new(&value)?);
+                let me = Self::from_inner(PathInner::new(&value)?);
                 me.validate_fs()?;
                 Ok(me)
             }
@@ -20,7 +20,7 @@ macro_rules! try_exist {
 
         impl $crate::TryExist<std::path::PathBuf> for $struct {
             fn try_exist(value: std::path::PathBuf) -> anyhow::Result<Self> {
-                let me = Self(PathInner::new_from_path(&value)?);
+                let me = Self::from_inner(PathInner::new_from_path(&value)?);
                 me.validate_fs()?;
                 Ok(me)
             }
@@ -0,0 +1,23 @@
+/// implement TryFromFs\<String\>, TryFromFs<&str>, TryFromFs\<PathBuf\>
+#[macro_export]
+macro_rules! try_from_fs {
+    ($struct:ident) => {
+        impl $crate::TryFromFs<String> for $struct {
+            fn try_from_fs(value: String) -> anyhow::Result<Self> {
+                Self::from_fs(PathInner::new(&value)?)
+            }
+        }
+
+        impl $crate::TryFromFs<&str> for $struct {
+            fn try_from_fs(value: &str) -> anyhow::Result<Self> {
+                Self::from_fs(PathInner::new(value)?)
+            }
+        }
+
+        impl $crate::TryFromFs<std::path::PathBuf> for $struct {
+            fn try_from_fs(value: std::path::PathBuf) -> anyhow::Result<Self> {
+                Self::from_fs(PathInner::new_from_path(&value)?)
+            }
+        }
+    };
+}
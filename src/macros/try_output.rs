@@ -0,0 +1,65 @@
+/// implement TryOutput\<String\>, TryOutput<&str>, TryOutput\<PathBuf\>
+#[macro_export]
+macro_rules! try_output {
+    ($struct:ident) => {
+        impl $crate::TryOutput<String> for $struct {
+            fn try_creatable(value: String) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_creatable_fs()?;
+                Ok(me)
+            }
+
+            fn try_fresh(value: String) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_fresh_fs()?;
+                Ok(me)
+            }
+
+            fn try_ensure_dir(value: String) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_dir_fs()?;
+                Ok(me)
+            }
+        }
+
+        impl $crate::TryOutput<&str> for $struct {
+            fn try_creatable(value: &str) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_creatable_fs()?;
+                Ok(me)
+            }
+
+            fn try_fresh(value: &str) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_fresh_fs()?;
+                Ok(me)
+            }
+
+            fn try_ensure_dir(value: &str) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_dir_fs()?;
+                Ok(me)
+            }
+        }
+
+        impl $crate::TryOutput<std::path::PathBuf> for $struct {
+            fn try_creatable(value: std::path::PathBuf) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_creatable_fs()?;
+                Ok(me)
+            }
+
+            fn try_fresh(value: std::path::PathBuf) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_fresh_fs()?;
+                Ok(me)
+            }
+
+            fn try_ensure_dir(value: std::path::PathBuf) -> anyhow::Result<Self> {
+                let me = Self::try_from(value)?;
+                me.0.ensure_dir_fs()?;
+                Ok(me)
+            }
+        }
+    };
+}
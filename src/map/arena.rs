@@ -0,0 +1,266 @@
+use std::{borrow::Cow, collections::HashMap, hash::Hash, marker::PhantomData, sync::Arc};
+
+use crate::{
+    inner::{folded_key, PathInner, PathStr},
+    marker::{Base, Folder, Kind, Rel},
+    os::{CurrentOS, OsGroup},
+    TypedPath, TypedPathRef,
+};
+
+/// Stores many paths compactly as a tree of `(parent, segment)` nodes where each
+/// segment name is kept once, so the common folders of the paths are shared.
+///
+/// Names are matched as the file systems of the OS do, see [OsGroup::CASE_SENSITIVE],
+/// so on Windows `C:\Users\` and `c:\users\` are the same node, which keeps
+/// the spelling it was first added with.
+///
+/// A path is added with [intern](Self::intern) which returns a small [ArenaPath]
+/// handle. Handles are `Copy` and are resolved to a path with [resolve](Self::resolve),
+/// while [parent](Self::parent) and [join](Self::join) work on the handles.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{AbsoluteFilePath, PathArena, RelativeFilePath};
+///
+/// let mut arena = PathArena::new();
+/// let file = arena.intern(&AbsoluteFilePath::try_from("/prj/src/main.rs")?);
+/// let src = arena.parent(file).unwrap();
+/// let lib = arena.join(src, &RelativeFilePath::try_from("lib.rs")?);
+///
+/// # #[cfg(not(windows))]
+/// assert_eq!(arena.resolve(lib), "/prj/src/lib.rs");
+/// assert_eq!(arena.parent(lib), Some(src));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PathArena {
+    nodes: Vec<Node>,
+    names: Vec<Arc<str>>,
+    /// The ids of the names by their [folded_key].
+    name_ids: HashMap<Arc<str>, u32>,
+    children: HashMap<(u32, u32), u32>,
+    case_sensitive: bool,
+}
+
+impl Default for PathArena {
+    fn default() -> Self {
+        Self::with_case(CurrentOS::CASE_SENSITIVE)
+    }
+}
+
+/// A root, such as `/` or an empty one for relative paths, has no parent.
+const NO_PARENT: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    parent: u32,
+    name: u32,
+}
+
+/// A path stored in a [PathArena]. Handles of the same arena are equal when
+/// their paths are.
+#[derive(Debug)]
+pub struct ArenaPath<B: Base, K: Kind> {
+    node: u32,
+    folder: bool,
+    t: PhantomData<(B, K)>,
+}
+
+impl<B: Base, K: Kind> ArenaPath<B, K> {
+    fn new(node: u32, folder: bool) -> Self {
+        Self {
+            node,
+            folder,
+            t: PhantomData,
+        }
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.folder
+    }
+}
+
+impl<B: Base, K: Kind> Clone for ArenaPath<B, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B: Base, K: Kind> Copy for ArenaPath<B, K> {}
+
+impl<B: Base, K: Kind> PartialEq for ArenaPath<B, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.folder == other.folder
+    }
+}
+
+impl<B: Base, K: Kind> Eq for ArenaPath<B, K> {}
+
+impl<B: Base, K: Kind> Hash for ArenaPath<B, K> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.node, self.folder).hash(state)
+    }
+}
+
+impl PathArena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn with_case(case_sensitive: bool) -> Self {
+        Self {
+            nodes: Vec::new(),
+            names: Vec::new(),
+            name_ids: HashMap::new(),
+            children: HashMap::new(),
+            case_sensitive,
+        }
+    }
+
+    /// The number of nodes, i.e. of distinct roots, folders and files.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn name(&self, node: u32) -> &str {
+        &self.names[self.nodes[node as usize].name as usize]
+    }
+
+    fn parent_node(&self, node: u32) -> Option<u32> {
+        Some(self.nodes[node as usize].parent).filter(|p| *p != NO_PARENT)
+    }
+
+    fn find(&self, parent: u32, name: &str) -> Option<u32> {
+        let name = *self.name_ids.get(&*folded_key(name, self.case_sensitive))?;
+        self.children.get(&(parent, name)).copied()
+    }
+
+    fn find_or_add(&mut self, parent: u32, name: &str) -> u32 {
+        if let Some(node) = self.find(parent, name) {
+            return node;
+        }
+        let key = folded_key(name, self.case_sensitive);
+        let name = match self.name_ids.get(&*key) {
+            Some(id) => *id,
+            None => {
+                let id = self.names.len() as u32;
+                let shared: Arc<str> = Arc::from(name);
+                let key = match key {
+                    Cow::Borrowed(_) => shared.clone(),
+                    Cow::Owned(key) => Arc::from(key),
+                };
+                self.names.push(shared);
+                self.name_ids.insert(key, id);
+                id
+            }
+        };
+        let node = self.nodes.len() as u32;
+        assert!(node != NO_PARENT, "too many paths in the arena");
+        self.nodes.push(Node { parent, name });
+        self.children.insert((parent, name), node);
+        node
+    }
+
+    /// The root and the segments of the path.
+    fn names(path: &PathStr<CurrentOS>) -> impl Iterator<Item = &str> {
+        std::iter::once(path.root()).chain(path.segments())
+    }
+
+    /// Adds the path, or finds it if it was added before, and returns its handle.
+    pub fn intern<B: Base, K: Kind>(&mut self, path: &TypedPathRef<B, K>) -> ArenaPath<B, K> {
+        let mut node = NO_PARENT;
+        for name in Self::names(&path.inner) {
+            node = self.find_or_add(node, name);
+        }
+        ArenaPath::new(node, path.inner.is_folder())
+    }
+
+    /// The handle of the path if it was added, which doesn't change the arena.
+    pub fn get<B: Base, K: Kind>(&self, path: &TypedPathRef<B, K>) -> Option<ArenaPath<B, K>> {
+        let mut node = NO_PARENT;
+        for name in Self::names(&path.inner) {
+            node = self.find(node, name)?;
+        }
+        Some(ArenaPath::new(node, path.inner.is_folder()))
+    }
+
+    /// The folder containing the path, `None` for a root or a relative path ending
+    /// in `..` whose parent isn't stored.
+    pub fn parent<B: Base, K: Kind>(&self, path: ArenaPath<B, K>) -> Option<ArenaPath<B, Folder>> {
+        if self.name(path.node) == ".." {
+            return None;
+        }
+        self.parent_node(path.node)
+            .map(|node| ArenaPath::new(node, true))
+    }
+
+    /// The last segment of the path, empty for a root.
+    pub fn file_name<B: Base, K: Kind>(&self, path: ArenaPath<B, K>) -> &str {
+        match self.parent_node(path.node) {
+            Some(_) => self.name(path.node),
+            None => "",
+        }
+    }
+
+    /// Appends the relative path to the folder, where a leading `..` goes up a folder
+    /// but never above the root of an absolute folder. Nodes are only added for
+    /// segments that weren't stored before.
+    pub fn join<B: Base, K: Kind>(
+        &mut self,
+        folder: ArenaPath<B, Folder>,
+        rel: &TypedPathRef<Rel, K>,
+    ) -> ArenaPath<B, K> {
+        let mut node = folder.node;
+        for segment in rel.inner.segments() {
+            node = match self.parent_node(node) {
+                _ if segment == "." => node,
+                Some(parent) if segment == ".." && self.name(node) != ".." => parent,
+                None if segment == ".." && CurrentOS::is_absolute(self.name(node)) => node,
+                _ => self.find_or_add(node, segment),
+            };
+        }
+        let folder = rel.inner.is_folder() || self.parent_node(node).is_none();
+        ArenaPath::new(node, folder)
+    }
+
+    /// Writes the path to `out`, which lets a buffer be reused for many paths.
+    /// The length is summed up first so that the names can be written from the
+    /// last one up to the root without collecting the nodes.
+    pub fn write_to<B: Base, K: Kind>(&self, path: ArenaPath<B, K>, out: &mut String) {
+        let start = out.len();
+        let mut len = self.name(path.node).len();
+        let mut node = path.node;
+        while let Some(parent) = self.parent_node(node) {
+            len += self.name(parent).len() + self.parent_node(parent).map_or(0, |_| 1);
+            node = parent;
+        }
+        // one byte chars to overwrite, so every range of them is on char boundaries
+        out.extend(std::iter::repeat_n('\0', len));
+        let mut end = start + len;
+        let mut node = path.node;
+        loop {
+            let name = self.name(node);
+            out.replace_range(end - name.len()..end, name);
+            end -= name.len();
+            let Some(parent) = self.parent_node(node) else {
+                break;
+            };
+            if self.parent_node(parent).is_some() {
+                end -= 1;
+                out.replace_range(end..end + 1, CurrentOS::SEP_STR);
+            }
+            node = parent;
+        }
+        if path.folder && out.len() > start && !out.ends_with(CurrentOS::SEP) {
+            out.push(CurrentOS::SEP);
+        }
+    }
+
+    /// The typed path of the handle.
+    pub fn resolve<B: Base, K: Kind>(&self, path: ArenaPath<B, K>) -> TypedPath<B, K> {
+        let mut out = String::new();
+        self.write_to(path, &mut out);
+        TypedPath::from_inner(PathInner::from_string(out))
+    }
+}
@@ -0,0 +1,418 @@
+#[cfg(test)]
+mod test;
+
+mod arena;
+mod set;
+
+use std::{
+    cmp::Ordering,
+    collections::{btree_map, BTreeMap},
+    fmt::{self, Debug},
+};
+
+use crate::{
+    inner::{cmp_str, PathStr},
+    marker::{Base, Folder},
+    os::{CurrentOS, OsGroup},
+    path::inner,
+    PathLike, TypedPathRef,
+};
+
+pub use arena::{ArenaPath, PathArena};
+pub use set::{PathSet, PathSetIter};
+
+/// A map keyed by paths that are stored in a trie of their segments, which makes
+/// queries such as the most specific folder containing a file cheap.
+///
+/// Iteration is in the order of the keys, i.e. a folder comes right before
+/// what is in it.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{AbsoluteFilePath, AbsoluteFolderPath, PathMap};
+///
+/// let mut settings = PathMap::new();
+/// settings.insert(AbsoluteFolderPath::try_from("/prj/")?, "default");
+/// settings.insert(AbsoluteFolderPath::try_from("/prj/gen/")?, "generated");
+///
+/// let file = AbsoluteFilePath::try_from("/prj/gen/api.rs")?;
+/// let (folder, setting) = settings.longest_prefix(&file).unwrap();
+/// assert_eq!(*setting, "generated");
+/// # #[cfg(not(windows))]
+/// assert_eq!(*folder, "/prj/gen/");
+/// # Ok(())
+/// # }
+/// ```
+pub struct PathMap<K, V> {
+    roots: BTreeMap<Segment, Node<K, V>>,
+    len: usize,
+}
+
+/// A segment as a trie key, ordered like the paths are.
+#[derive(Clone)]
+struct Segment(String);
+
+impl PartialEq for Segment {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Segment {}
+
+impl PartialOrd for Segment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Segment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_str(&self.0, &other.0, CurrentOS::CASE_SENSITIVE)
+    }
+}
+
+/// The keys ending at the node, i.e. a file and a folder with the same segments,
+/// and the nodes of the next segments.
+#[derive(Clone)]
+struct Node<K, V> {
+    entries: Vec<(K, V)>,
+    children: BTreeMap<Segment, Node<K, V>>,
+}
+
+impl<K, V> Default for Node<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K, V> Node<K, V> {
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.children.is_empty()
+    }
+
+    fn drain_into(self, removed: &mut Vec<(K, V)>) {
+        removed.extend(self.entries);
+        for child in self.children.into_values() {
+            child.drain_into(removed);
+        }
+    }
+}
+
+fn segments(path: &PathStr<CurrentOS>) -> impl Iterator<Item = Segment> + '_ {
+    std::iter::once(path.root())
+        .chain(path.segments())
+        .map(|s| Segment(s.to_string()))
+}
+
+impl<K, V> PathMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            roots: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.roots.clear();
+        self.len = 0;
+    }
+
+    /// The entries in the order of their keys.
+    pub fn iter(&self) -> PathMapIter<'_, K, V> {
+        PathMapIter::new(&self.roots)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+impl<K: PathLike, V> PathMap<K, V> {
+    fn node<Q: PathLike + ?Sized>(&self, path: &Q) -> Option<&Node<K, V>> {
+        let mut segments = segments(inner(path));
+        let mut node = self.roots.get(&segments.next()?)?;
+        for segment in segments {
+            node = node.children.get(&segment)?;
+        }
+        Some(node)
+    }
+
+    fn node_mut<Q: PathLike + ?Sized>(&mut self, path: &Q) -> Option<&mut Node<K, V>> {
+        let mut segments = segments(inner(path));
+        let mut node = self.roots.get_mut(&segments.next()?)?;
+        for segment in segments {
+            node = node.children.get_mut(&segment)?;
+        }
+        Some(node)
+    }
+
+    /// Inserts the value and returns the previous one of the key, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut segments = segments(inner(&key));
+        let root = segments.next().expect("a path has a root, even if empty");
+        let mut node = self.roots.entry(root).or_default();
+        for segment in segments {
+            node = node.children.entry(segment).or_default();
+        }
+        let same = |(k, _): &&mut (K, V)| inner(k) == inner(&key);
+        if let Some((_, old)) = node.entries.iter_mut().find(same) {
+            return Some(std::mem::replace(old, value));
+        }
+        node.entries.push((key, value));
+        node.entries
+            .sort_by(|(a, _), (b, _)| inner(a).cmp(inner(b)));
+        self.len += 1;
+        None
+    }
+
+    pub fn get<Q: PathLike + ?Sized>(&self, key: &Q) -> Option<&V> {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value<Q: PathLike + ?Sized>(&self, key: &Q) -> Option<(&K, &V)> {
+        let node = self.node(key)?;
+        let (k, v) = node.entries.iter().find(|(k, _)| inner(k) == inner(key))?;
+        Some((k, v))
+    }
+
+    pub fn get_mut<Q: PathLike + ?Sized>(&mut self, key: &Q) -> Option<&mut V> {
+        let node = self.node_mut(key)?;
+        let (_, v) = node
+            .entries
+            .iter_mut()
+            .find(|(k, _)| inner(k) == inner(key))?;
+        Some(v)
+    }
+
+    pub fn contains_key<Q: PathLike + ?Sized>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the key and returns its value, if it was in the map.
+    pub fn remove<Q: PathLike + ?Sized>(&mut self, key: &Q) -> Option<V> {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    pub fn remove_entry<Q: PathLike + ?Sized>(&mut self, key: &Q) -> Option<(K, V)> {
+        let node = self.node_mut(key)?;
+        let index = node
+            .entries
+            .iter()
+            .position(|(k, _)| inner(k) == inner(key))?;
+        let removed = node.entries.remove(index);
+        self.len -= 1;
+        self.prune(key);
+        Some(removed)
+    }
+
+    /// Removes the nodes left empty along the path.
+    fn prune<Q: PathLike + ?Sized>(&mut self, path: &Q) {
+        fn prune_node<K, V>(node: &mut Node<K, V>, segments: &[Segment]) {
+            let Some((first, rest)) = segments.split_first() else {
+                return;
+            };
+            if let Some(child) = node.children.get_mut(first) {
+                prune_node(child, rest);
+                if child.is_empty() {
+                    node.children.remove(first);
+                }
+            }
+        }
+        let segments = segments(inner(path)).collect::<Vec<_>>();
+        if let Some(root) = self.roots.get_mut(&segments[0]) {
+            prune_node(root, &segments[1..]);
+            if root.is_empty() {
+                self.roots.remove(&segments[0]);
+            }
+        }
+    }
+
+    /// The entry with the deepest key that contains the path, i.e. the most specific
+    /// folder that the path is in. A file key only contains itself.
+    pub fn longest_prefix<Q: PathLike + ?Sized>(&self, path: &Q) -> Option<(&K, &V)> {
+        let path = inner(path);
+        let depth = path.depth();
+        let mut segments = segments(path);
+        let mut node = self.roots.get(&segments.next()?)?;
+        let mut level = 0;
+        let mut found = None;
+        loop {
+            let contains = |k: &PathStr<CurrentOS>| {
+                if k.is_folder() {
+                    level < depth || path.is_folder()
+                } else {
+                    k == path
+                }
+            };
+            if let Some((k, v)) = node.entries.iter().rev().find(|(k, _)| contains(inner(k))) {
+                found = Some((k, v));
+            }
+            let Some(segment) = segments.next() else {
+                return found;
+            };
+            match node.children.get(&segment) {
+                Some(child) => node = child,
+                None => return found,
+            }
+            level += 1;
+        }
+    }
+
+    /// The entries with keys inside the folder, in the order of the keys. The folder
+    /// itself isn't included.
+    pub fn descendants<B: Base>(&self, folder: &TypedPathRef<B, Folder>) -> PathMapIter<'_, K, V> {
+        match self.node(folder) {
+            Some(node) => PathMapIter::new(&node.children),
+            None => PathMapIter::empty(),
+        }
+    }
+
+    /// Removes the folder and everything in it, and returns the removed entries
+    /// in the order of their keys.
+    pub fn remove_subtree<B: Base>(&mut self, folder: &TypedPathRef<B, Folder>) -> Vec<(K, V)> {
+        let mut removed = Vec::new();
+        let Some(node) = self.node_mut(folder) else {
+            return removed;
+        };
+        let (folders, files) = std::mem::take(&mut node.entries)
+            .into_iter()
+            .partition(|(k, _)| inner(k).is_folder());
+        node.entries = files;
+        removed.extend(folders);
+        for child in std::mem::take(&mut node.children).into_values() {
+            child.drain_into(&mut removed);
+        }
+        self.len -= removed.len();
+        self.prune(folder);
+        removed
+    }
+}
+
+impl<K, V> Default for PathMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for PathMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            roots: self.roots.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for PathMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PathLike, V: PartialEq> PartialEq for PathMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|((k1, v1), (k2, v2))| inner(k1) == inner(k2) && v1 == v2)
+    }
+}
+
+impl<K: PathLike, V> FromIterator<(K, V)> for PathMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: PathLike, V> Extend<(K, V)> for PathMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a PathMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = PathMapIter<'a, K, V>;
+
+    fn into_iter(self) -> PathMapIter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K, V> IntoIterator for PathMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut entries = Vec::with_capacity(self.len);
+        for root in self.roots.into_values() {
+            root.drain_into(&mut entries);
+        }
+        entries.into_iter()
+    }
+}
+
+/// The entries of a [PathMap] in the order of their keys, depth first.
+pub struct PathMapIter<'a, K, V> {
+    entries: std::slice::Iter<'a, (K, V)>,
+    stack: Vec<btree_map::Values<'a, Segment, Node<K, V>>>,
+}
+
+impl<'a, K, V> PathMapIter<'a, K, V> {
+    fn new(nodes: &'a BTreeMap<Segment, Node<K, V>>) -> Self {
+        Self {
+            entries: [].iter(),
+            stack: vec![nodes.values()],
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
+            entries: [].iter(),
+            stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for PathMapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((k, v)) = self.entries.next() {
+                return Some((k, v));
+            }
+            let nodes = self.stack.last_mut()?;
+            match nodes.next() {
+                Some(node) => {
+                    self.entries = node.entries.iter();
+                    self.stack.push(node.children.values());
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::{self, Debug};
+
+use crate::{
+    marker::{Base, Folder},
+    PathLike, TypedPathRef,
+};
+
+use super::{PathMap, PathMapIter};
+
+/// A set of paths stored in a trie of their segments, see [PathMap].
+pub struct PathSet<K> {
+    map: PathMap<K, ()>,
+}
+
+impl<K> PathSet<K> {
+    pub fn new() -> Self {
+        Self {
+            map: PathMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// The paths in order.
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.map.keys()
+    }
+}
+
+impl<K: PathLike> PathSet<K> {
+    /// Adds the path and returns true if it wasn't in the set.
+    pub fn insert(&mut self, path: K) -> bool {
+        self.map.insert(path, ()).is_none()
+    }
+
+    pub fn contains<Q: PathLike + ?Sized>(&self, path: &Q) -> bool {
+        self.map.contains_key(path)
+    }
+
+    pub fn get<Q: PathLike + ?Sized>(&self, path: &Q) -> Option<&K> {
+        self.map.get_key_value(path).map(|(k, _)| k)
+    }
+
+    /// Removes the path and returns true if it was in the set.
+    pub fn remove<Q: PathLike + ?Sized>(&mut self, path: &Q) -> bool {
+        self.map.remove(path).is_some()
+    }
+
+    /// The deepest path that contains `path`, see [PathMap::longest_prefix].
+    pub fn longest_prefix<Q: PathLike + ?Sized>(&self, path: &Q) -> Option<&K> {
+        self.map.longest_prefix(path).map(|(k, _)| k)
+    }
+
+    /// The paths inside the folder, in order.
+    pub fn descendants<B: Base>(&self, folder: &TypedPathRef<B, Folder>) -> PathSetIter<'_, K> {
+        PathSetIter(self.map.descendants(folder))
+    }
+
+    /// Removes the folder and everything in it, and returns the removed paths in order.
+    pub fn remove_subtree<B: Base>(&mut self, folder: &TypedPathRef<B, Folder>) -> Vec<K> {
+        let removed = self.map.remove_subtree(folder);
+        removed.into_iter().map(|(k, _)| k).collect()
+    }
+}
+
+/// The paths of a [PathSet] in order.
+pub struct PathSetIter<'a, K>(PathMapIter<'a, K, ()>);
+
+impl<'a, K> Iterator for PathSetIter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.0.next().map(|(k, _)| k)
+    }
+}
+
+impl<K> Default for PathSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone> Clone for PathSet<K> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K: Debug> Debug for PathSet<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PathLike> PartialEq for PathSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: PathLike> FromIterator<K> for PathSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: PathLike> Extend<K> for PathSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for path in iter {
+            self.insert(path);
+        }
+    }
+}
+
+impl<K> IntoIterator for PathSet<K> {
+    type Item = K;
+    type IntoIter = std::iter::Map<std::vec::IntoIter<(K, ())>, fn((K, ())) -> K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter().map(|(k, _)| k)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    AbsoluteFilePath, AbsoluteFolderPath, AbsolutePath, PathArena, PathLike, PathMap, PathSet,
+    RelativeFilePath, RelativeFolderPath,
+};
+
+fn folder(path: &str) -> AbsoluteFolderPath {
+    AbsoluteFolderPath::try_from(path).unwrap()
+}
+
+fn file(path: &str) -> AbsoluteFilePath {
+    AbsoluteFilePath::try_from(path).unwrap()
+}
+
+#[test]
+fn insert_get_remove() {
+    let mut map = PathMap::new();
+    assert_eq!(map.insert(folder("/a/"), 1), None);
+    assert_eq!(map.insert(folder("/a/b/"), 2), None);
+    assert_eq!(map.insert(folder("/a/./b/"), 3), Some(2));
+    assert_eq!(map.len(), 2);
+
+    assert_eq!(map.get(&folder("/a/b/")), Some(&3));
+    assert_eq!(map.get(&file("/a/b")), None);
+    *map.get_mut(&folder("/a/")).unwrap() += 10;
+    assert_eq!(map.get(&*folder("/a/")), Some(&11));
+
+    assert_eq!(map.remove(&folder("/a/b/")), Some(3));
+    assert_eq!(map.remove(&folder("/a/b/")), None);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.roots.values().next().unwrap().children.len(), 1);
+    assert!(map.remove(&folder("/a/")).is_some());
+    assert!(map.is_empty() && map.roots.is_empty());
+}
+
+#[test]
+fn longest_prefix() {
+    let map = [
+        (AbsolutePath::from(folder("/")), "root"),
+        (folder("/prj/").into(), "prj"),
+        (folder("/prj/gen/").into(), "gen"),
+        (file("/prj/gen/special.rs").into(), "special"),
+    ]
+    .into_iter()
+    .collect::<PathMap<_, _>>();
+
+    let found = |path: &str| {
+        map.longest_prefix(&AbsolutePath::try_from(path).unwrap())
+            .map(|(_, v)| *v)
+    };
+    assert_eq!(found("/prj/gen/api.rs"), Some("gen"));
+    assert_eq!(found("/prj/gen/"), Some("gen"));
+    assert_eq!(found("/prj/gen"), Some("prj"));
+    assert_eq!(found("/prj/gen/special.rs"), Some("special"));
+    assert_eq!(found("/prj/gen/special.rs/"), Some("gen"));
+    assert_eq!(found("/prj/src/main.rs"), Some("prj"));
+    assert_eq!(found("/other/file"), Some("root"));
+
+    let (key, _) = map.longest_prefix(&file("/prj/x")).unwrap();
+    assert_eq!(key.as_str(), "/prj/");
+
+    let rel = RelativeFolderPath::try_from("prj/").unwrap();
+    assert!(map.longest_prefix(&rel).is_none());
+}
+
+fn strs(paths: Vec<&AbsolutePath>) -> Vec<&str> {
+    paths.iter().map(|p| p.as_str()).collect()
+}
+
+#[test]
+fn ordered_iteration_and_subtrees() {
+    let mut set = ["/a-b/", "/a/b/c", "/a/", "/a/b", "/a/b/", "/z", "/a/b/d/"]
+        .into_iter()
+        .map(|p| AbsolutePath::try_from(p).unwrap())
+        .collect::<PathSet<_>>();
+
+    let mut sorted = set.iter().cloned().collect::<Vec<_>>();
+    sorted.sort();
+    assert_eq!(set.iter().cloned().collect::<Vec<_>>(), sorted);
+    assert_eq!(
+        strs(set.iter().collect()),
+        ["/a/", "/a/b", "/a/b/", "/a/b/c", "/a/b/d/", "/a-b/", "/z"]
+    );
+
+    assert_eq!(
+        strs(set.descendants(&folder("/a/")).collect()),
+        ["/a/b", "/a/b/", "/a/b/c", "/a/b/d/"]
+    );
+    assert_eq!(set.descendants(&folder("/x/")).count(), 0);
+
+    let removed = set.remove_subtree(&folder("/a/b/"));
+    assert_eq!(
+        strs(removed.iter().collect()),
+        ["/a/b/", "/a/b/c", "/a/b/d/"]
+    );
+    assert_eq!(strs(set.iter().collect()), ["/a/", "/a/b", "/a-b/", "/z"]);
+    assert_eq!(set.len(), 4);
+    assert!(set.contains(&file("/a/b")));
+}
+
+#[test]
+fn arena_intern_resolve() {
+    let mut arena = PathArena::new();
+    let main = arena.intern(&file("/prj/src/main.rs"));
+    let lib = arena.intern(&file("/prj/src/lib.rs"));
+    let src = arena.intern(&folder("/prj/src/"));
+    assert_eq!(arena.node_count(), 5);
+    assert_eq!(arena.intern(&file("/prj/./src/main.rs")), main);
+
+    assert_eq!(arena.resolve(main), "/prj/src/main.rs");
+    assert_eq!(arena.resolve(src), "/prj/src/");
+    assert_eq!(arena.file_name(lib), "lib.rs");
+    assert_eq!(arena.parent(main), Some(src));
+    assert_eq!(arena.parent(lib), Some(src));
+
+    let root = arena.parent(arena.parent(src).unwrap()).unwrap();
+    assert_eq!(arena.resolve(root), "/");
+    assert_eq!(arena.parent(root), None);
+
+    assert_eq!(arena.get(&file("/prj/src/main.rs")), Some(main));
+    assert_eq!(
+        arena.get(&file("/prj/src")),
+        Some(arena.intern(&file("/prj/src")))
+    );
+    assert_eq!(arena.get(&file("/prj/other.rs")), None);
+    assert_eq!(arena.node_count(), 5);
+
+    let mut out = String::new();
+    arena.write_to(main, &mut out);
+    arena.write_to(src, &mut out);
+    assert_eq!(out, "/prj/src/main.rs/prj/src/");
+
+    out.clear();
+    let rel = arena.intern(&RelativeFilePath::try_from("../é/ü.txt").unwrap());
+    arena.write_to(root, &mut out);
+    arena.write_to(rel, &mut out);
+    assert_eq!(out, "/../é/ü.txt");
+}
+
+#[test]
+fn arena_ignores_case() {
+    let mut arena = PathArena::with_case(false);
+    let users = arena.intern(&folder("/Users/Me/"));
+    assert_eq!(arena.intern(&folder("/users/ME/")), users);
+    assert_eq!(arena.get(&folder("/USERS/me/")), Some(users));
+    assert_eq!(arena.node_count(), 3);
+    assert_eq!(arena.resolve(users), "/Users/Me/");
+
+    let mut arena = PathArena::new();
+    let users = arena.intern(&folder("/Users/"));
+    assert_ne!(arena.intern(&folder("/users/")), users);
+}
+
+#[test]
+fn arena_join() {
+    let mut arena = PathArena::new();
+    let src = arena.intern(&folder("/prj/src/"));
+    let rel = |p| RelativeFilePath::try_from(p).unwrap();
+    let rel_folder = |p| RelativeFolderPath::try_from(p).unwrap();
+
+    let main = arena.join(src, &rel("bin/main.rs"));
+    assert_eq!(arena.resolve(main), "/prj/src/bin/main.rs");
+    assert_eq!(arena.intern(&file("/prj/src/bin/main.rs")), main);
+
+    let count = arena.node_count();
+    assert_eq!(arena.join(src, &rel("../src/./bin/main.rs")), main);
+    assert_eq!(arena.node_count(), count);
+
+    let root = arena.join(src, &rel_folder("../../../"));
+    assert_eq!(arena.resolve(root), "/");
+    let a = arena.join(root, &rel("a"));
+    assert_eq!(arena.resolve(a), "/a");
+
+    let base = arena.intern(&rel_folder("a/"));
+    let up = arena.join(base, &rel_folder("../../b/"));
+    assert_eq!(arena.resolve(up), "../b/");
+    assert_eq!(arena.parent(arena.parent(up).unwrap()), None);
+}
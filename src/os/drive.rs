@@ -29,3 +29,12 @@ pub fn win_drive(path: &str) -> Option<char> {
         None
     }
 }
+
+/// Removes the `\\?\` prefix that windows adds to canonicalized paths
+/// when the rest of the path is a regular drive path.
+pub(crate) fn remove_verbatim_prefix(path: &str) -> &str {
+    match path.strip_prefix(r"\\?\") {
+        Some(rest) if has_drive(rest) => rest,
+        _ => path,
+    }
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::os::OsGroup;
 
-pub(crate) fn contract<OS: OsGroup>(path: &str) -> Result<(Option<char>, &str)> {
+pub(crate) fn contract<OS: OsGroup + ?Sized>(path: &str) -> Result<(Option<char>, &str)> {
     let home_rel = remove_abs_start::<OS>(path, &OS::home()?);
     let cwd_rel = remove_abs_start::<OS>(path, &OS::current()?);
     Ok(match (home_rel, cwd_rel) {
@@ -14,14 +14,75 @@ pub(crate) fn contract<OS: OsGroup>(path: &str) -> Result<(Option<char>, &str)>
     })
 }
 
-fn remove_abs_start<'a, OS: OsGroup>(path: &'a str, start: &str) -> Option<&'a str> {
-    if path.starts_with(start) {
-        let mut pos = start.len();
-        if path[pos..].starts_with(OS::SEP) {
-            pos += 1;
+fn remove_abs_start<'a, OS: OsGroup + ?Sized>(path: &'a str, start: &str) -> Option<&'a str> {
+    let start = trim_root::<OS>(start);
+    let rest = path.strip_prefix(start)?;
+    let sep_len = root_boundary(rest)?;
+    Some(&rest[sep_len..])
+}
+
+/// The root without its trailing separator, except for the file system root.
+fn trim_root<OS: OsGroup + ?Sized>(root: &str) -> &str {
+    match root.strip_suffix(OS::SEP) {
+        Some(trimmed) if !trimmed.is_empty() && !trimmed.ends_with(':') => trimmed,
+        _ => root,
+    }
+}
+
+/// Checks that `rest`, the text following a root, doesn't continue the root's
+/// last segment (`/home/test` is not a root of `/home/tester`), and returns the
+/// length of the separators to skip, which can be escaped in serialised text.
+fn root_boundary(rest: &str) -> Option<usize> {
+    let seps = rest
+        .chars()
+        .take(2)
+        .take_while(|c| *c == '/' || *c == '\\')
+        .count();
+    if seps > 0 {
+        return Some(seps);
+    }
+    match rest.chars().next() {
+        Some(c) if c.is_alphanumeric() || "_-.~$%".contains(c) => None,
+        _ => Some(0),
+    }
+}
+
+/// Replaces all occurrences of the `roots` in `text` with their placeholders
+/// followed by a `/`. The longest root wins when several match and on windows
+/// the roots are also found with `/` and escaped `\\` separators.
+pub(crate) fn redact<OS: OsGroup>(text: &str, roots: &[(String, String)]) -> String {
+    let mut forms = Vec::new();
+    for (root, placeholder) in roots {
+        let root = trim_root::<OS>(root);
+        forms.push((root.to_string(), placeholder.as_str()));
+        if OS::SEP == '\\' {
+            forms.push((root.replace('\\', "/"), placeholder));
+            forms.push((root.replace('\\', "\\\\"), placeholder));
+        }
+    }
+    forms.sort_by_key(|(root, _)| std::cmp::Reverse(root.len()));
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut pos = 0;
+    'text: while pos < text.len() {
+        let rest = &text[pos..];
+        for (root, placeholder) in &forms {
+            let Some(after) = rest.strip_prefix(root.as_str()) else {
+                continue;
+            };
+            let Some(sep_len) = root_boundary(after) else {
+                continue;
+            };
+            redacted.push_str(placeholder);
+            if sep_len > 0 {
+                redacted.push('/');
+            }
+            pos += root.len() + sep_len;
+            continue 'text;
         }
-        Some(&path[pos..])
-    } else {
-        None
+        let c = rest.chars().next().unwrap_or_default();
+        redacted.push(c);
+        pos += c.len_utf8();
     }
+    redacted
 }
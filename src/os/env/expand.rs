@@ -23,7 +23,7 @@ impl Start {
         }
     }
 }
-pub(crate) fn expand<OS: OsGroup>(path: &str) -> Result<Cow<str>> {
+pub(crate) fn expand<OS: OsGroup>(path: &str) -> Result<Cow<'_, str>> {
     let start = Start::from(path);
 
     let path: Cow<str> = match start {
@@ -85,7 +85,7 @@ pub(crate) fn expand<OS: OsGroup>(path: &str) -> Result<Cow<str>> {
     Ok(Cow::Owned(expanded))
 }
 
-fn prefix_current_dir<P: OsGroup>(path: &str) -> Result<Cow<str>> {
+fn prefix_current_dir<P: OsGroup>(path: &str) -> Result<Cow<'_, str>> {
     let mut cwd = P::current().context("could not resolve the current working directory")?;
     if !cwd.ends_with(SLASH) && !path.starts_with(SLASH) {
         cwd.push(P::SEP);
@@ -94,7 +94,7 @@ fn prefix_current_dir<P: OsGroup>(path: &str) -> Result<Cow<str>> {
     Ok(Cow::Owned(cwd))
 }
 
-fn prefix_home_dir<P: OsGroup>(path: &str) -> Result<Cow<str>> {
+fn prefix_home_dir<P: OsGroup>(path: &str) -> Result<Cow<'_, str>> {
     let mut home = P::home().context("could not resolve the current working directory")?;
     if !home.ends_with(SLASH) && !path.starts_with(SLASH) {
         home.push(P::SEP);
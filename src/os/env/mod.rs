@@ -5,6 +5,6 @@ mod contract;
 mod expand;
 mod var;
 
-pub(crate) use contract::contract;
+pub(crate) use contract::{contract, redact};
 pub(crate) use expand::expand;
 pub(crate) use var::var;
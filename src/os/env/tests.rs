@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use super::expand;
+use super::{contract, expand, redact};
 use crate::os::{LinTestOS, WinTestOS};
 
 #[test]
@@ -57,7 +57,7 @@ fn expand_envs_win() {
 }
 
 #[cfg(test)]
-fn exp_ok_win(path: &str) -> Cow<str> {
+fn exp_ok_win(path: &str) -> Cow<'_, str> {
     expand::<WinTestOS>(path).unwrap()
 }
 
@@ -118,7 +118,7 @@ fn exp_envs_lin() {
     assert_eq!(exp_ok_lin("~/dir"), "/home/test/dir");
 }
 
-fn exp_ok_lin(path: &str) -> Cow<str> {
+fn exp_ok_lin(path: &str) -> Cow<'_, str> {
     expand::<LinTestOS>(path).unwrap()
 }
 
@@ -128,3 +128,59 @@ fn exp_err_lin(path: &str) -> String {
         .to_string()
         .replace('\\', "/")
 }
+
+#[test]
+fn contract_respects_segments() {
+    assert_eq!(
+        contract::<LinTestOS>("/home/test/dir").unwrap(),
+        (Some('~'), "dir")
+    );
+    assert_eq!(
+        contract::<LinTestOS>("/home/test").unwrap(),
+        (Some('~'), "")
+    );
+    assert_eq!(
+        contract::<LinTestOS>("/home/tester/dir").unwrap(),
+        (None, "/home/tester/dir")
+    );
+    assert_eq!(
+        contract::<WinTestOS>(r"C:\User\test\dir").unwrap(),
+        (Some('~'), "dir")
+    );
+}
+
+#[test]
+fn redact_lin() {
+    let roots = roots(&[("/home/test/", "[HOME]"), ("/home/test/prj", "[PRJ]")]);
+    let red = |text: &str| redact::<LinTestOS>(text, &roots);
+
+    assert_eq!(red("/home/test/a.txt"), "[HOME]/a.txt");
+    assert_eq!(red("/home/test"), "[HOME]");
+    assert_eq!(red("/home/test/prj/src/"), "[PRJ]/src/");
+    assert_eq!(red("/home/tester/a.txt"), "/home/tester/a.txt");
+    assert_eq!(
+        red(r#"{"a":"/home/test/prj/x","b":"/home/test/y"}"#),
+        r#"{"a":"[PRJ]/x","b":"[HOME]/y"}"#
+    );
+    assert_eq!(
+        red("failed: /home/test/prj: not found"),
+        "failed: [PRJ]: not found"
+    );
+}
+
+#[test]
+fn redact_win() {
+    let roots = roots(&[(r"C:\User\test\", "[HOME]")]);
+    let red = |text: &str| redact::<WinTestOS>(text, &roots);
+
+    assert_eq!(red(r"C:\User\test\a.txt"), "[HOME]/a.txt");
+    assert_eq!(red("C:/User/test/a.txt"), "[HOME]/a.txt");
+    assert_eq!(red(r#""C:\\User\\test\\a.txt""#), r#""[HOME]/a.txt""#);
+}
+
+fn roots(roots: &[(&str, &str)]) -> Vec<(String, String)> {
+    roots
+        .iter()
+        .map(|(r, p)| (r.to_string(), p.to_string()))
+        .collect()
+}
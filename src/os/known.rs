@@ -0,0 +1,136 @@
+use std::io;
+
+use anyhow::{Context, Result};
+
+/// Per-user folders that applications use for their files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KnownFolder {
+    Config,
+    Cache,
+    Data,
+    State,
+    Runtime,
+    Documents,
+    Downloads,
+}
+
+impl KnownFolder {
+    fn xdg_var(self) -> &'static str {
+        match self {
+            KnownFolder::Config => "XDG_CONFIG_HOME",
+            KnownFolder::Cache => "XDG_CACHE_HOME",
+            KnownFolder::Data => "XDG_DATA_HOME",
+            KnownFolder::State => "XDG_STATE_HOME",
+            KnownFolder::Runtime => "XDG_RUNTIME_DIR",
+            KnownFolder::Documents => "XDG_DOCUMENTS_DIR",
+            KnownFolder::Downloads => "XDG_DOWNLOAD_DIR",
+        }
+    }
+
+    /// The default relative to the home folder according to the XDG spec
+    fn xdg_default(self) -> Option<&'static str> {
+        match self {
+            KnownFolder::Config => Some(".config"),
+            KnownFolder::Cache => Some(".cache"),
+            KnownFolder::Data => Some(".local/share"),
+            KnownFolder::State => Some(".local/state"),
+            KnownFolder::Runtime | KnownFolder::Documents | KnownFolder::Downloads => None,
+        }
+    }
+}
+
+/// Resolves a known folder following the
+/// [XDG Base Directory spec](https://specifications.freedesktop.org/basedir-spec/latest/),
+/// where `var` looks up environment variables. The user folders, such as documents,
+/// are read from `user-dirs.dirs` in the config folder.
+pub(crate) fn xdg_folder<V>(folder: KnownFolder, var: V, home: &str) -> Result<Option<String>>
+where
+    V: Fn(&str) -> Option<String>,
+{
+    if matches!(folder, KnownFolder::Documents | KnownFolder::Downloads) {
+        let config = xdg_folder(KnownFolder::Config, var, home)?.unwrap_or_default();
+        let file = format!("{}/user-dirs.dirs", config.trim_end_matches('/'));
+        let content = match std::fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("could not read file: {file}")),
+        };
+        return Ok(user_dir(&content, folder.xdg_var(), home));
+    }
+    // relative paths are invalid according to the spec and should be ignored
+    if let Some(value) = var(folder.xdg_var()).filter(|v| v.starts_with('/')) {
+        return Ok(Some(value));
+    }
+    Ok(folder
+        .xdg_default()
+        .map(|default| format!("{}/{default}", home.trim_end_matches('/'))))
+}
+
+/// Finds `key` in the content of a `user-dirs.dirs` file, where the lines have the
+/// format `XDG_DOWNLOAD_DIR="$HOME/Downloads"`. A folder set to the home
+/// folder itself is disabled.
+pub(crate) fn user_dir(content: &str, key: &str, home: &str) -> Option<String> {
+    let value = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, v)| v.trim().trim_matches('"'))?;
+
+    let path = match value.strip_prefix("$HOME") {
+        Some(rest) => {
+            let rest = rest.trim_matches('/');
+            if rest.is_empty() {
+                return None;
+            }
+            format!("{}/{rest}", home.trim_end_matches('/'))
+        }
+        None if value.starts_with('/') => value.to_string(),
+        None => return None,
+    };
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(key: &str) -> Option<String> {
+        match key {
+            "XDG_CONFIG_HOME" => Some("/etc/conf".into()),
+            "XDG_CACHE_HOME" => Some("rel/cache".into()),
+            "XDG_STATE_HOME" => Some("".into()),
+            _ => None,
+        }
+    }
+
+    fn xdg(folder: KnownFolder) -> Option<String> {
+        xdg_folder(folder, env, "/home/test/").unwrap()
+    }
+
+    #[test]
+    fn xdg_folders() {
+        assert_eq!(xdg(KnownFolder::Config).unwrap(), "/etc/conf");
+        assert_eq!(xdg(KnownFolder::Cache).unwrap(), "/home/test/.cache");
+        assert_eq!(xdg(KnownFolder::Data).unwrap(), "/home/test/.local/share");
+        assert_eq!(xdg(KnownFolder::State).unwrap(), "/home/test/.local/state");
+        assert_eq!(xdg(KnownFolder::Runtime), None);
+    }
+
+    #[test]
+    fn user_dirs() {
+        let content = r#"
+# This file is written by xdg-user-dirs-update
+XDG_DESKTOP_DIR="$HOME/Desktop"
+XDG_DOWNLOAD_DIR="$HOME/Downloads"
+XDG_DOCUMENTS_DIR="/data/docs"
+XDG_MUSIC_DIR="$HOME/"
+"#;
+        let dir = |key| user_dir(content, key, "/home/test");
+        assert_eq!(dir("XDG_DOWNLOAD_DIR").unwrap(), "/home/test/Downloads");
+        assert_eq!(dir("XDG_DOCUMENTS_DIR").unwrap(), "/data/docs");
+        assert_eq!(dir("XDG_MUSIC_DIR"), None);
+        assert_eq!(dir("XDG_VIDEOS_DIR"), None);
+    }
+}
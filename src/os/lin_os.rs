@@ -8,6 +8,7 @@ pub(crate) struct LinOS {}
 impl OsGroup for LinOS {
     const SEP: char = '/';
     const SEP_STR: &'static str = "/";
+    const CASE_SENSITIVE: bool = true;
 
     fn home() -> Result<String> {
         home_dir()
@@ -18,14 +18,20 @@ pub use test_os::{LinTestOS, WinTestOS};
 
 mod drive;
 mod env;
+mod known;
 
 use anyhow::Result;
-pub(crate) use env::contract;
+pub(crate) use drive::{remove_verbatim_prefix, win_drive};
 pub(crate) use env::expand;
+pub(crate) use env::{contract, redact};
+pub(crate) use known::KnownFolder;
 
 pub(crate) trait OsGroup {
     const SEP: char;
     const SEP_STR: &'static str;
+    /// False when the file systems of the OS treat `A` and `a` as the same,
+    /// which makes paths compare and hash case-insensitively.
+    const CASE_SENSITIVE: bool;
 
     fn is_absolute(path: &str) -> bool;
     fn start_of_relative_path(path: &str) -> usize;
@@ -35,32 +41,18 @@ pub(crate) trait OsGroup {
     fn current() -> Result<String>;
     fn drive_letter() -> Result<char>;
 
-    fn contract(path: &str) -> Result<(Option<char>, &str)> {
-        let home_rel = Self::remove_abs_start(path, &Self::home()?);
-        let cwd_rel = Self::remove_abs_start(path, &Self::current()?);
-        Ok(match (home_rel, cwd_rel) {
-            (Some(home), Some(cwd)) if home.len() < cwd.len() => (Some('~'), home),
-            (Some(_), Some(cwd)) => (Some('.'), cwd),
-            (Some(home), None) => (Some('~'), home),
-            (None, Some(cwd)) => (Some('.'), cwd),
-            (None, None) => (None, path),
-        })
-    }
-    fn remove_abs_start<'a>(path: &'a str, start: &str) -> Option<&'a str> {
-        if path.starts_with(start) {
-            let mut pos = start.len();
-            if path[pos..].starts_with(Self::SEP) {
-                pos += 1;
-            }
-            Some(&path[pos..])
-        } else {
-            None
-        }
+    /// The known folder or None if the OS has no such folder or it isn't configured,
+    /// where `var` looks up environment variables.
+    fn known_folder(
+        folder: KnownFolder,
+        var: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Option<String>> {
+        known::xdg_folder(folder, var, &Self::home()?)
     }
 
     fn as_contracted(path: &str, do_contract: bool) -> (Option<char>, &str) {
         if do_contract && Self::is_absolute(path) {
-            match Self::contract(path) {
+            match contract::<Self>(path) {
                 Ok(s) => s,
                 Err(_) => (None, path),
             }
@@ -8,6 +8,7 @@ pub struct LinTestOS {}
 impl OsGroup for LinTestOS {
     const SEP: char = '/';
     const SEP_STR: &'static str = "/";
+    const CASE_SENSITIVE: bool = true;
 
     fn current() -> anyhow::Result<String> {
         Ok(String::from("/var/test"))
@@ -39,6 +40,7 @@ pub struct WinTestOS {}
 impl OsGroup for WinTestOS {
     const SEP: char = '\\';
     const SEP_STR: &'static str = "\\";
+    const CASE_SENSITIVE: bool = false;
 
     fn is_absolute(path: &str) -> bool {
         super::is_absolute_win(path)
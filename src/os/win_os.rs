@@ -1,6 +1,7 @@
-use super::OsGroup;
+use super::{KnownFolder, OsGroup};
 use crate::ext::PathBufExt;
 use anyhow::Result;
+use windows_sys::{core::GUID, Win32::UI::Shell};
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct WinOS {}
@@ -8,6 +9,7 @@ pub(crate) struct WinOS {}
 impl OsGroup for WinOS {
     const SEP: char = '\\';
     const SEP_STR: &'static str = "\\";
+    const CASE_SENSITIVE: bool = false;
 
     fn home() -> Result<String> {
         home_dir()
@@ -17,6 +19,25 @@ impl OsGroup for WinOS {
         Ok(std::env::current_dir()?.try_to_string()?)
     }
 
+    /// Windows has no environment variables for the known folders, so `var` isn't used.
+    fn known_folder(
+        folder: KnownFolder,
+        _var: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Option<String>> {
+        let (id, name) = match folder {
+            KnownFolder::Config | KnownFolder::Data => {
+                (&Shell::FOLDERID_RoamingAppData, "roaming app data folder")
+            }
+            KnownFolder::Cache | KnownFolder::State => {
+                (&Shell::FOLDERID_LocalAppData, "local app data folder")
+            }
+            KnownFolder::Runtime => return Ok(None),
+            KnownFolder::Documents => (&Shell::FOLDERID_Documents, "documents folder"),
+            KnownFolder::Downloads => (&Shell::FOLDERID_Downloads, "downloads folder"),
+        };
+        known_folder_path(id, name).map(Some)
+    }
+
     fn drive_letter() -> Result<char> {
         use anyhow::bail;
 
@@ -42,6 +63,10 @@ impl OsGroup for WinOS {
 }
 
 pub fn home_dir() -> Result<String> {
+    known_folder_path(&Shell::FOLDERID_Profile, "user's home directory")
+}
+
+fn known_folder_path(id: &GUID, name: &str) -> Result<String> {
     unsafe {
         use anyhow::bail;
         use std::ffi::c_void;
@@ -51,15 +76,10 @@ pub fn home_dir() -> Result<String> {
         use windows_sys as windows;
 
         use windows::Win32;
-        use windows::Win32::UI::Shell;
 
         let mut path_ptr: windows::core::PWSTR = std::ptr::null_mut();
-        let result = Shell::SHGetKnownFolderPath(
-            &Shell::FOLDERID_Profile,
-            0,
-            Win32::Foundation::HANDLE::default(),
-            &mut path_ptr,
-        );
+        let result =
+            Shell::SHGetKnownFolderPath(id, 0, Win32::Foundation::HANDLE::default(), &mut path_ptr);
         if result == 0 {
             let len = windows::Win32::Globalization::lstrlenW(path_ptr) as usize;
             let path = slice::from_raw_parts(path_ptr, len);
@@ -72,14 +92,11 @@ pub fn home_dir() -> Result<String> {
                     }
                     Ok(s)
                 }
-                Err(s) => bail!(
-                    "invalid characters in user home directory: {}",
-                    s.to_string_lossy()
-                ),
+                Err(s) => bail!("invalid characters in {name}: {}", s.to_string_lossy()),
             }
         } else {
             windows::Win32::System::Com::CoTaskMemFree(path_ptr as *const c_void);
-            bail!("could not resolve the user's home directory")
+            bail!("could not resolve the {name}")
         }
     }
 }
@@ -1,27 +1,33 @@
 use crate::os::CurrentOS;
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{Abs, File, TypedPath};
 use crate::{
-    all_files, all_paths, inner::PathInner, serde_exist, serde_expanded, try_exist, try_from,
+    inner::PathInner, serde_exist, serde_expanded, serde_output, try_exist, try_from_fs, try_output,
 };
-use crate::{AbsoluteFolderPath, RelativeFolderPath};
+use crate::{AbsoluteFolderPath, AbsolutePath, KindChanged, RelativeFolderPath};
 use anyhow::{ensure, Result};
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct AbsoluteFilePath(pub(crate) PathInner<CurrentOS>);
+pub type AbsoluteFilePath = TypedPath<Abs, File>;
+pub type AbsoluteFilePathRef = TypedPathRef<Abs, File>;
 
-all_paths!(AbsoluteFilePath);
-all_files!(AbsoluteFilePath);
-try_from!(AbsoluteFilePath);
 try_exist!(AbsoluteFilePath);
+try_from_fs!(AbsoluteFilePath);
 serde_exist!(AbsoluteFilePath);
 serde_expanded!(AbsoluteFilePath);
+serde_output!(AbsoluteFilePath);
+try_output!(AbsoluteFilePath);
+
+impl AbsoluteFilePathRef {
+    pub fn exists(&self) -> bool {
+        let p = self.inner.as_path();
+        p.exists() && p.is_file()
+    }
+}
 
 impl AbsoluteFilePath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_absolute()?;
-        self.0.ensure_file()?;
-        Ok(self)
+    pub(crate) fn from_fs(inner: PathInner<CurrentOS>) -> Result<Self> {
+        inner.ensure_file_fs()?;
+        Self::from_inner(inner).validate()
     }
 
     pub(crate) fn validate_fs(&self) -> Result<()> {
@@ -31,20 +37,32 @@ impl AbsoluteFilePath {
         Ok(())
     }
 
-    pub fn exists(&self) -> bool {
-        let p = self.0.as_path();
-        p.exists() && p.is_file()
+    /// Resolves all symlinks using the file system. Fails with [KindChanged]
+    /// if the path turns out to point to a folder.
+    pub fn canonicalize(&self) -> Result<AbsoluteFilePath> {
+        let canonical = self.0.canonicalized()?;
+        if canonical.is_folder() {
+            return Err(KindChanged {
+                path: AbsolutePath::from_inner(self.0.clone()),
+                resolved: AbsolutePath::from_inner(canonical),
+            }
+            .into());
+        }
+        Ok(Self::from_inner(canonical))
     }
 
-    pub fn dropping_file(&self) -> AbsoluteFolderPath {
-        AbsoluteFolderPath(self.0.drop_file())
+    /// True if both paths point to the same file on disk, ex: via a symlink or hard link
+    pub fn is_same_file(&self, other: &AbsoluteFilePath) -> Result<bool> {
+        self.0.is_same_file(&other.0)
     }
 
     pub fn removing_root(&self, root: AbsoluteFolderPath) -> Option<RelativeFolderPath> {
-        self.0.remove_root(root.as_str()).map(RelativeFolderPath)
+        self.0
+            .remove_root(&root.0.path)
+            .map(RelativeFolderPath::from_inner)
     }
 
     pub fn to_relative(&self, from_segment_index: usize) -> RelativeFolderPath {
-        RelativeFolderPath(self.0.relative_from(from_segment_index))
+        RelativeFolderPath::from_inner(self.0.relative_from(from_segment_index))
     }
 }
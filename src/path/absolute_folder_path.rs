@@ -1,28 +1,34 @@
 use crate::os::CurrentOS;
-use crate::{all_dirs, with_file, RelativeFolderPath};
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{Abs, Folder, TypedPath};
+use crate::IsAbsolute;
 use crate::{
-    all_paths, inner::PathInner, serde_exist, serde_expanded, try_exist, try_from, AbsoluteFilePath,
+    inner::PathInner, serde_exist, serde_expanded, serde_output, try_exist, try_from_fs, try_output,
 };
+use crate::{AbsolutePath, KindChanged, RelativeFolderPath};
 use anyhow::{ensure, Result};
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct AbsoluteFolderPath(pub(crate) PathInner<CurrentOS>);
+pub type AbsoluteFolderPath = TypedPath<Abs, Folder>;
+pub type AbsoluteFolderPathRef = TypedPathRef<Abs, Folder>;
 
-all_paths!(AbsoluteFolderPath);
-all_dirs!(AbsoluteFolderPath);
-try_from!(AbsoluteFolderPath);
 try_exist!(AbsoluteFolderPath);
-with_file!(AbsoluteFolderPath, AbsoluteFilePath);
+try_from_fs!(AbsoluteFolderPath);
 serde_exist!(AbsoluteFolderPath);
 serde_expanded!(AbsoluteFolderPath);
+serde_output!(AbsoluteFolderPath);
+try_output!(AbsoluteFolderPath);
+
+impl AbsoluteFolderPathRef {
+    pub fn exists(&self) -> bool {
+        let p = self.inner.as_path();
+        p.exists() && p.is_dir()
+    }
+}
 
 impl AbsoluteFolderPath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_absolute()?;
-        self.0.ensure_folder()?;
-        Ok(self)
+    pub(crate) fn from_fs(mut inner: PathInner<CurrentOS>) -> Result<Self> {
+        inner.ensure_folder_fs()?;
+        Self::from_inner(inner).validate()
     }
 
     pub(crate) fn validate_fs(&self) -> Result<()> {
@@ -32,12 +38,40 @@ impl AbsoluteFolderPath {
         Ok(())
     }
 
-    pub fn exists(&self) -> bool {
-        let p = self.0.as_path();
-        p.exists() && p.is_dir()
+    /// Resolves all symlinks using the file system. Fails with [KindChanged]
+    /// if the path turns out to point to a file.
+    pub fn canonicalize(&self) -> Result<AbsoluteFolderPath> {
+        let canonical = self.0.canonicalized()?;
+        if !canonical.is_folder() {
+            return Err(KindChanged {
+                path: AbsolutePath::from_inner(self.0.clone()),
+                resolved: AbsolutePath::from_inner(canonical),
+            }
+            .into());
+        }
+        Ok(Self::from_inner(canonical))
+    }
+
+    /// True if both paths point to the same folder on disk, ex: via a symlink
+    pub fn is_same_file(&self, other: &AbsoluteFolderPath) -> Result<bool> {
+        self.0.is_same_file(&other.0)
+    }
+
+    /// True if `path` is inside this folder, comparing whole segments.
+    /// A folder is not its own ancestor.
+    pub fn is_ancestor_of<P: IsAbsolute>(&self, path: &P) -> bool {
+        path.starts_with(self) && path.depth() > self.0.depth()
+    }
+
+    /// The deepest folder that contains all the paths, where a folder path
+    /// contains itself. None if there are no paths or they are on different drives.
+    pub fn common_ancestor(paths: &[AbsolutePath]) -> Option<AbsoluteFolderPath> {
+        PathInner::common_ancestor(paths.iter().map(|p| &p.0)).map(Self::from_inner)
     }
 
     pub fn removing_root(&self, root: AbsoluteFolderPath) -> Option<RelativeFolderPath> {
-        self.0.remove_root(&root.0.path).map(RelativeFolderPath)
+        self.0
+            .remove_root(&root.0.path)
+            .map(RelativeFolderPath::from_inner)
     }
 }
@@ -1,24 +1,33 @@
 use crate::os::CurrentOS;
-use crate::{all_paths, inner::PathInner, serde_exist, serde_expanded, try_exist, try_from};
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{Abs, AnyKind, TypedPath};
+use crate::{
+    inner::PathInner, serde_exist, serde_expanded, serde_output, try_exist, try_from_fs, try_output,
+};
 use crate::{AbsoluteFilePath, AbsoluteFolderPath};
 use anyhow::{ensure, Result};
 use either::Either;
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct AbsolutePath(pub(crate) PathInner<CurrentOS>);
+pub type AbsolutePath = TypedPath<Abs, AnyKind>;
+pub type AbsolutePathRef = TypedPathRef<Abs, AnyKind>;
 
-all_paths!(AbsolutePath);
-try_from!(AbsolutePath);
 try_exist!(AbsolutePath);
+try_from_fs!(AbsolutePath);
 serde_exist!(AbsolutePath);
 serde_expanded!(AbsolutePath);
+serde_output!(AbsolutePath);
+try_output!(AbsolutePath);
+
+impl AbsolutePathRef {
+    pub fn exists(&self) -> bool {
+        self.inner.as_path().exists()
+    }
+}
 
 impl AbsolutePath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_absolute()?;
-        Ok(self)
+    pub(crate) fn from_fs(mut inner: PathInner<CurrentOS>) -> Result<Self> {
+        inner.infer_folder_fs();
+        Self::from_inner(inner).validate()
     }
 
     pub(crate) fn validate_fs(&self) -> Result<()> {
@@ -27,14 +36,42 @@ impl AbsolutePath {
         Ok(())
     }
 
-    pub fn exists(&self) -> bool {
-        self.0.as_path().exists()
+    /// Resolves all symlinks using the file system. The result is a folder
+    /// path if it points to a directory.
+    pub fn canonicalize(&self) -> Result<AbsolutePath> {
+        Ok(Self::from_inner(self.0.canonicalized()?))
+    }
+
+    /// Resolves the path as written using the file system, before it is normalised.
+    /// Unlike [canonicalize](Self::canonicalize) a `..` after a symlink, as in
+    /// `/a/link/../b`, goes up from the target of the link like the OS does.
+    pub fn canonicalize_str(path: &str) -> Result<AbsolutePath> {
+        Ok(Self::from_inner(PathInner::new_canonical(path)?))
     }
 
     pub fn to_concrete(self) -> Either<AbsoluteFolderPath, AbsoluteFilePath> {
         match self.0.is_folder() {
-            true => Either::Left(AbsoluteFolderPath(self.0)),
-            false => Either::Right(AbsoluteFilePath(self.0)),
+            true => Either::Left(AbsoluteFolderPath::from_inner(self.0)),
+            false => Either::Right(AbsoluteFilePath::from_inner(self.0)),
         }
     }
+
+    /// Like `to_concrete` but a path without a trailing slash is
+    /// considered a folder if it is an existing folder in the file system.
+    pub fn to_concrete_fs(mut self) -> Either<AbsoluteFolderPath, AbsoluteFilePath> {
+        self.0.infer_folder_fs();
+        self.to_concrete()
+    }
+}
+
+impl From<AbsoluteFolderPath> for AbsolutePath {
+    fn from(value: AbsoluteFolderPath) -> Self {
+        Self::from_inner(value.0)
+    }
+}
+
+impl From<AbsoluteFilePath> for AbsolutePath {
+    fn from(value: AbsoluteFilePath) -> Self {
+        Self::from_inner(value.0)
+    }
 }
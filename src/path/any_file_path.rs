@@ -1,44 +1,29 @@
-use crate::os::CurrentOS;
-use crate::{all_paths, inner::PathInner, try_from};
-use crate::{AbsoluteFilePath, AnyFolderPath, AnyPath, RelativeFilePath};
-use anyhow::Result;
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{AnyBase, File, TypedPath};
+use crate::{AbsoluteFilePath, AnyPath, RelativeFilePath};
 use either::Either;
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct AnyFilePath(pub(crate) PathInner<CurrentOS>);
-
-all_paths!(AnyFilePath);
-try_from!(AnyFilePath);
+pub type AnyFilePath = TypedPath<AnyBase, File>;
+pub type AnyFilePathRef = TypedPathRef<AnyBase, File>;
 
 impl AnyFilePath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_file()?;
-        Ok(self)
-    }
-
     pub fn to_concrete(self) -> Either<AbsoluteFilePath, RelativeFilePath> {
         match self.0.is_absolute() {
-            true => Either::Left(AbsoluteFilePath(self.0)),
-            false => Either::Right(RelativeFilePath(self.0)),
+            true => Either::Left(AbsoluteFilePath::from_inner(self.0)),
+            false => Either::Right(RelativeFilePath::from_inner(self.0)),
         }
     }
-
-    pub fn drop_file(&self) -> AnyFolderPath {
-        AnyFolderPath(self.0.drop_file())
-    }
 }
 
 impl From<RelativeFilePath> for AnyFilePath {
     fn from(value: RelativeFilePath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
 impl From<AbsoluteFilePath> for AnyFilePath {
     fn from(value: AbsoluteFilePath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
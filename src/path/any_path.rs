@@ -1,18 +1,12 @@
-use crate::os::CurrentOS;
-use crate::{all_paths, inner::PathInner, try_from};
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{AnyBase, AnyKind, TypedPath};
 use crate::{
     AbsoluteFilePath, AbsoluteFolderPath, AnyFilePath, AnyFolderPath, RelativeFilePath,
     RelativeFolderPath,
 };
-use anyhow::Result;
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct AnyPath(pub(crate) PathInner<CurrentOS>);
-
-all_paths!(AnyPath);
-try_from!(AnyPath);
+pub type AnyPath = TypedPath<AnyBase, AnyKind>;
+pub type AnyPathRef = TypedPathRef<AnyBase, AnyKind>;
 
 pub enum ConcretePath {
     AbsDir(AbsoluteFolderPath),
@@ -40,50 +34,46 @@ impl AnyPath {
 
     pub fn to_concrete(self) -> ConcretePath {
         match (self.is_abs(), self.is_dir()) {
-            (true, true) => ConcretePath::AbsDir(AbsoluteFolderPath(self.0)),
-            (false, true) => ConcretePath::RelDir(RelativeFolderPath(self.0)),
-            (true, false) => ConcretePath::AbsFile(AbsoluteFilePath(self.0)),
-            (false, false) => ConcretePath::RelFile(RelativeFilePath(self.0)),
+            (true, true) => ConcretePath::AbsDir(AbsoluteFolderPath::from_inner(self.0)),
+            (false, true) => ConcretePath::RelDir(RelativeFolderPath::from_inner(self.0)),
+            (true, false) => ConcretePath::AbsFile(AbsoluteFilePath::from_inner(self.0)),
+            (false, false) => ConcretePath::RelFile(RelativeFilePath::from_inner(self.0)),
         }
     }
-
-    pub(crate) fn validate(self) -> Result<Self> {
-        Ok(self)
-    }
 }
 
 impl From<RelativeFolderPath> for AnyPath {
     fn from(value: RelativeFolderPath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
 impl From<AbsoluteFolderPath> for AnyPath {
     fn from(value: AbsoluteFolderPath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
 impl From<RelativeFilePath> for AnyPath {
     fn from(value: RelativeFilePath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
 impl From<AbsoluteFilePath> for AnyPath {
     fn from(value: AbsoluteFilePath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
 impl From<AnyFolderPath> for AnyPath {
     fn from(value: AnyFolderPath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
 
 impl From<AnyFilePath> for AnyPath {
     fn from(value: AnyFilePath) -> Self {
-        Self(value.0)
+        Self::from_inner(value.0)
     }
 }
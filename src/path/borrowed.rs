@@ -0,0 +1,164 @@
+use std::{
+    borrow::{Borrow, Cow},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    inner::PathStr,
+    os::CurrentOS,
+    path::typed::{check, marker::Within, Base, File, Folder, Kind, TypedPath},
+};
+
+/// The borrowed form of a [TypedPath], like [Path](std::path::Path) for
+/// [PathBuf](std::path::PathBuf). Each owned path type has a borrowed one
+/// with `Ref` appended, ex: [AbsoluteFilePathRef](crate::AbsoluteFilePathRef).
+///
+/// An owned path derefs to its borrowed form, so functions that take a
+/// `&AbsoluteFolderPathRef` accept both, and [Borrow] lets a
+/// `HashMap<AbsoluteFilePath, _>` be queried with a borrowed key.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use std::collections::HashMap;
+/// use x_path::{AbsoluteFilePath, AbsoluteFilePathRef, AbsoluteFolderPathRef, PathLike};
+///
+/// fn folder_of(file: &AbsoluteFilePathRef) -> &AbsoluteFolderPathRef {
+///     file.dropping_file()
+/// }
+///
+/// let file = AbsoluteFilePath::try_from("/dir/file.txt")?;
+/// # #[cfg(not(windows))]
+/// assert_eq!(folder_of(&file).as_str(), "/dir/");
+///
+/// let mut sizes = HashMap::new();
+/// sizes.insert(file.clone(), 10);
+/// let key: &AbsoluteFilePathRef = &file;
+/// assert_eq!(sizes.get(key), Some(&10));
+/// # Ok(())
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct TypedPathRef<B: Base, K: Kind> {
+    t: PhantomData<(B, K)>,
+    pub(crate) inner: PathStr<CurrentOS>,
+}
+
+impl<B: Base, K: Kind> TypedPathRef<B, K> {
+    /// Views a valid path as a borrowed typed path, which is sound because
+    /// TypedPathRef is a transparent wrapper around the PathStr.
+    pub(crate) fn from_path_str(inner: &PathStr<CurrentOS>) -> &Self {
+        unsafe { &*(inner as *const PathStr<CurrentOS> as *const Self) }
+    }
+
+    /// Borrows as a wider type without any checks, ex: an absolute file as any path.
+    pub fn widen<B2: Base, K2: Kind>(&self) -> &TypedPathRef<B2, K2>
+    where
+        B: Within<B2>,
+        K: Within<K2>,
+    {
+        TypedPathRef::from_path_str(&self.inner)
+    }
+
+    /// Borrows as any other type, which fails if the path isn't of that type.
+    pub fn narrow<B2: Base, K2: Kind>(&self) -> Result<&TypedPathRef<B2, K2>> {
+        check::<B2, K2>(&self.inner)?;
+        Ok(TypedPathRef::from_path_str(&self.inner))
+    }
+
+    /// The folder containing this path. It is borrowed from this path, except
+    /// when the path ends with `..` and the parent is one more level up.
+    pub fn parent(&self) -> Option<Cow<'_, TypedPathRef<B, Folder>>> {
+        Some(match self.inner.parent()? {
+            Cow::Borrowed(parent) => Cow::Borrowed(TypedPathRef::from_path_str(parent)),
+            Cow::Owned(parent) => Cow::Owned(TypedPath::from_inner(parent)),
+        })
+    }
+
+    /// The folder with the first `count` segments, borrowed from this path.
+    /// `None` if the path doesn't have that many folder segments.
+    pub fn leading(&self, count: usize) -> Option<&TypedPathRef<B, Folder>> {
+        self.inner.leading(count).map(TypedPathRef::from_path_str)
+    }
+}
+
+impl<B: Base> TypedPathRef<B, File> {
+    /// The folder that the file is in.
+    pub fn dropping_file(&self) -> &TypedPathRef<B, Folder> {
+        TypedPathRef::from_path_str(self.inner.drop_file())
+    }
+
+    pub fn file_name(&self) -> &str {
+        self.inner.file_name()
+    }
+
+    pub fn file_stem(&self) -> &str {
+        self.inner.file_stem()
+    }
+
+    pub fn extension(&self) -> Option<&str> {
+        self.inner.extension()
+    }
+}
+
+impl<B: Base, K: Kind> Deref for TypedPath<B, K> {
+    type Target = TypedPathRef<B, K>;
+
+    fn deref(&self) -> &TypedPathRef<B, K> {
+        TypedPathRef::from_path_str(&self.0)
+    }
+}
+
+impl<B: Base, K: Kind> Borrow<TypedPathRef<B, K>> for TypedPath<B, K> {
+    fn borrow(&self) -> &TypedPathRef<B, K> {
+        self
+    }
+}
+
+impl<B: Base, K: Kind> AsRef<TypedPathRef<B, K>> for TypedPath<B, K> {
+    fn as_ref(&self) -> &TypedPathRef<B, K> {
+        self
+    }
+}
+
+impl<B: Base, K: Kind> ToOwned for TypedPathRef<B, K> {
+    type Owned = TypedPath<B, K>;
+
+    fn to_owned(&self) -> TypedPath<B, K> {
+        TypedPath::from_inner(self.inner.to_owned())
+    }
+}
+
+impl<B: Base, K: Kind> AsRef<std::path::Path> for TypedPathRef<B, K> {
+    fn as_ref(&self) -> &std::path::Path {
+        self.inner.as_path()
+    }
+}
+
+impl<B: Base, K: Kind> std::fmt::Display for TypedPathRef<B, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<B: Base, K: Kind> std::fmt::Debug for TypedPathRef<B, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}{}PathRef({:#?})", B::NAME, K::NAME, &self.inner)
+        } else {
+            write!(f, "{}{}PathRef({:?})", B::NAME, K::NAME, &self.inner)
+        }
+    }
+}
+
+impl<B: Base, K: Kind> Serialize for TypedPathRef<B, K> {
+    fn serialize<S>(&self, ser: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.inner.serialize(ser)
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::{inner::PathStr, os::CurrentOS, path::inner, PathLike};
+
+/// Compares, orders and hashes the wrapped path ignoring case, as the default
+/// file systems of macOS do. On Windows paths already compare this way.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use std::collections::HashSet;
+/// use x_path::{AbsoluteFilePath, CaseInsensitive};
+///
+/// let mut seen = HashSet::new();
+/// seen.insert(CaseInsensitive(AbsoluteFilePath::try_from("/Users/Me/notes.txt")?));
+/// assert!(seen.contains(&CaseInsensitive(AbsoluteFilePath::try_from("/users/me/NOTES.txt")?)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitive<P>(pub P);
+
+impl<P: PathLike> CaseInsensitive<P> {
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+
+    fn inner(&self) -> &PathStr<CurrentOS> {
+        inner(&self.0)
+    }
+}
+
+impl<P: PathLike> PartialEq for CaseInsensitive<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner().eq_case(other.inner(), false)
+    }
+}
+
+impl<P: PathLike> Eq for CaseInsensitive<P> {}
+
+impl<P: PathLike> PartialOrd for CaseInsensitive<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: PathLike> Ord for CaseInsensitive<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner().cmp_case(other.inner(), false)
+    }
+}
+
+impl<P: PathLike> Hash for CaseInsensitive<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner().hash_case(state, false)
+    }
+}
+
+impl<P> Deref for CaseInsensitive<P> {
+    type Target = P;
+
+    fn deref(&self) -> &P {
+        &self.0
+    }
+}
+
+impl<P: Display> Display for CaseInsensitive<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,139 @@
+//! Equality, order and hashing of paths. Paths of different types compare by their
+//! normalised strings when the types overlap, see [Overlaps].
+
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    inner::PathInner,
+    os::{CurrentOS, OsGroup},
+    path::{
+        borrowed::TypedPathRef,
+        typed::{marker::Overlaps, Base, Kind, TypedPath},
+    },
+};
+
+impl<B, K, B2, K2> PartialEq<TypedPathRef<B2, K2>> for TypedPathRef<B, K>
+where
+    B: Base + Overlaps<B2>,
+    K: Kind + Overlaps<K2>,
+    B2: Base,
+    K2: Kind,
+{
+    fn eq(&self, other: &TypedPathRef<B2, K2>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<B, K, B2, K2> PartialOrd<TypedPathRef<B2, K2>> for TypedPathRef<B, K>
+where
+    B: Base + Overlaps<B2>,
+    K: Kind + Overlaps<K2>,
+    B2: Base,
+    K2: Kind,
+{
+    fn partial_cmp(&self, other: &TypedPathRef<B2, K2>) -> Option<Ordering> {
+        Some(self.inner.cmp(&other.inner))
+    }
+}
+
+impl<B: Base, K: Kind> Eq for TypedPathRef<B, K> {}
+
+impl<B: Base, K: Kind> Ord for TypedPathRef<B, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+/// Hashes the same as the owned path, as required by [Borrow](std::borrow::Borrow).
+impl<B: Base, K: Kind> Hash for TypedPathRef<B, K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+/// The owned paths compare as their borrowed forms.
+macro_rules! owned_cmp {
+    ($lhs:ident, $rhs:ident, $l:ident, $r:ident) => {
+        impl<B, K, B2, K2> PartialEq<$rhs<B2, K2>> for $lhs<B, K>
+        where
+            B: Base + Overlaps<B2>,
+            K: Kind + Overlaps<K2>,
+            B2: Base,
+            K2: Kind,
+        {
+            fn eq(&self, other: &$rhs<B2, K2>) -> bool {
+                $l(self) == $r(other)
+            }
+        }
+
+        impl<B, K, B2, K2> PartialOrd<$rhs<B2, K2>> for $lhs<B, K>
+        where
+            B: Base + Overlaps<B2>,
+            K: Kind + Overlaps<K2>,
+            B2: Base,
+            K2: Kind,
+        {
+            fn partial_cmp(&self, other: &$rhs<B2, K2>) -> Option<Ordering> {
+                $l(self).partial_cmp($r(other))
+            }
+        }
+    };
+}
+
+fn owned<B: Base, K: Kind>(path: &TypedPath<B, K>) -> &TypedPathRef<B, K> {
+    path
+}
+
+fn borrowed<B: Base, K: Kind>(path: &TypedPathRef<B, K>) -> &TypedPathRef<B, K> {
+    path
+}
+
+owned_cmp!(TypedPath, TypedPath, owned, owned);
+owned_cmp!(TypedPath, TypedPathRef, owned, borrowed);
+owned_cmp!(TypedPathRef, TypedPath, borrowed, owned);
+
+impl<B: Base, K: Kind> Eq for TypedPath<B, K> {}
+
+impl<B: Base, K: Kind> Ord for TypedPath<B, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        owned(self).cmp(owned(other))
+    }
+}
+
+impl<B: Base, K: Kind> Hash for TypedPath<B, K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        owned(self).hash(state)
+    }
+}
+
+/// Compares with the string as a path, segment by segment and without allocating.
+/// A string starting with `~` or `.` or with an environment variable is expanded
+/// first, which looks up the home or current folder on each comparison.
+impl<B: Base, K: Kind> PartialEq<&str> for TypedPathRef<B, K> {
+    fn eq(&self, other: &&str) -> bool {
+        self.inner
+            .eq_str(other, CurrentOS::CASE_SENSITIVE)
+            .unwrap_or_else(|| PathInner::new(other).is_ok_and(|other| self.inner == *other))
+    }
+}
+
+impl<B: Base, K: Kind> PartialEq<&str> for TypedPath<B, K> {
+    fn eq(&self, other: &&str) -> bool {
+        owned(self) == other
+    }
+}
+
+impl<B: Base, K: Kind> PartialEq<TypedPathRef<B, K>> for &str {
+    fn eq(&self, other: &TypedPathRef<B, K>) -> bool {
+        other == self
+    }
+}
+
+impl<B: Base, K: Kind> PartialEq<TypedPath<B, K>> for &str {
+    fn eq(&self, other: &TypedPath<B, K>) -> bool {
+        other == self
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+
+use crate::{
+    inner::PathInner,
+    os::{CurrentOS, KnownFolder, OsGroup},
+    AbsoluteFolderPath,
+};
+
+/// The user's documents and downloads folders, when configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserDirs {
+    pub documents: Option<AbsoluteFolderPath>,
+    pub downloads: Option<AbsoluteFolderPath>,
+}
+
+/// The known folders follow the [XDG Base Directory spec](https://specifications.freedesktop.org/basedir-spec/latest/)
+/// on unix, using the `XDG_*` environment variables when they are set to absolute
+/// paths and the defaults relative to the home folder otherwise. On windows they are
+/// the known folders `RoamingAppData` (config, data) and `LocalAppData` (cache, state).
+///
+/// The folders are not required to exist.
+impl AbsoluteFolderPath {
+    /// `$XDG_CONFIG_HOME` or `~/.config/`
+    pub fn config_dir() -> Result<Self> {
+        KnownFolders::new().config_dir()
+    }
+
+    /// `$XDG_CACHE_HOME` or `~/.cache/`
+    pub fn cache_dir() -> Result<Self> {
+        KnownFolders::new().cache_dir()
+    }
+
+    /// `$XDG_DATA_HOME` or `~/.local/share/`
+    pub fn data_dir() -> Result<Self> {
+        KnownFolders::new().data_dir()
+    }
+
+    /// `$XDG_STATE_HOME` or `~/.local/state/`
+    pub fn state_dir() -> Result<Self> {
+        KnownFolders::new().state_dir()
+    }
+
+    /// `$XDG_RUNTIME_DIR`, which has no default, and None on windows.
+    pub fn runtime_dir() -> Result<Option<Self>> {
+        KnownFolders::new().runtime_dir()
+    }
+
+    /// The documents and downloads folders from `user-dirs.dirs` in the config
+    /// folder on unix and the known folders on windows.
+    pub fn user_dirs() -> Result<UserDirs> {
+        KnownFolders::new().user_dirs()
+    }
+}
+
+/// Resolves the known folders like the functions of [AbsoluteFolderPath] such as
+/// [config_dir](AbsoluteFolderPath::config_dir), but with the environment variables
+/// looked up by the given function, ex: to test a configuration without changing
+/// the environment of the process. On windows the variables aren't used.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::KnownFolders;
+///
+/// let known = KnownFolders::from_env(|key| match key {
+///     "XDG_CONFIG_HOME" => Some("/etc/app/".to_string()),
+///     _ => None,
+/// });
+/// # #[cfg(unix)]
+/// assert_eq!(known.config_dir()?, "/etc/app/");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct KnownFolders<V> {
+    var: V,
+}
+
+impl KnownFolders<fn(&str) -> Option<String>> {
+    /// Looks up the environment variables of the process.
+    pub fn new() -> Self {
+        Self::from_env(|key| std::env::var(key).ok())
+    }
+}
+
+impl Default for KnownFolders<fn(&str) -> Option<String>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Fn(&str) -> Option<String>> KnownFolders<V> {
+    pub fn from_env(var: V) -> Self {
+        Self { var }
+    }
+
+    pub fn config_dir(&self) -> Result<AbsoluteFolderPath> {
+        self.known(KnownFolder::Config, "config")
+    }
+
+    pub fn cache_dir(&self) -> Result<AbsoluteFolderPath> {
+        self.known(KnownFolder::Cache, "cache")
+    }
+
+    pub fn data_dir(&self) -> Result<AbsoluteFolderPath> {
+        self.known(KnownFolder::Data, "data")
+    }
+
+    pub fn state_dir(&self) -> Result<AbsoluteFolderPath> {
+        self.known(KnownFolder::State, "state")
+    }
+
+    pub fn runtime_dir(&self) -> Result<Option<AbsoluteFolderPath>> {
+        self.known_opt(KnownFolder::Runtime)
+    }
+
+    pub fn user_dirs(&self) -> Result<UserDirs> {
+        Ok(UserDirs {
+            documents: self.known_opt(KnownFolder::Documents)?,
+            downloads: self.known_opt(KnownFolder::Downloads)?,
+        })
+    }
+
+    fn known(&self, folder: KnownFolder, name: &str) -> Result<AbsoluteFolderPath> {
+        self.known_opt(folder)?
+            .with_context(|| format!("could not resolve the {name} folder"))
+    }
+
+    fn known_opt(&self, folder: KnownFolder) -> Result<Option<AbsoluteFolderPath>> {
+        let Some(path) = CurrentOS::known_folder(folder, &self.var)? else {
+            return Ok(None);
+        };
+        let mut inner = PathInner::new_expanded(&path)?;
+        if !inner.is_folder() {
+            inner.path.edit(|path| path.push(CurrentOS::SEP));
+        }
+        AbsoluteFolderPath::from_inner(inner).validate().map(Some)
+    }
+}
@@ -0,0 +1,387 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{
+    inner::{PathInner, PathStr, PathString},
+    iter::Segments,
+    os::CurrentOS,
+    path::{
+        borrowed::TypedPathRef,
+        typed::{Abs, Base, File, Folder, Kind, Rel, TypedPath},
+    },
+    AbsoluteFolderPath, AnyPathRef, Extensions, RelativeFolderPath, RelativePath, StrValues,
+};
+
+pub(crate) fn inner<P: PathLike + ?Sized>(path: &P) -> &PathStr<CurrentOS> {
+    &path.path_ref().inner
+}
+
+fn inner_mut<P: sealed::PathMut>(path: &mut P) -> &mut PathInner<CurrentOS> {
+    PathInner::from_path_string_mut(path.path_string_mut(sealed::Token(())))
+}
+
+mod sealed {
+    use crate::inner::PathString;
+
+    /// Only this crate can create a token, so the string of a path can't be
+    /// changed from outside, not even through a `FileLike` bound.
+    pub struct Token(pub(super) ());
+
+    /// Changes to the string must keep it a valid path of the same type.
+    pub trait PathMut {
+        fn path_string_mut(&mut self, token: Token) -> &mut PathString;
+    }
+}
+
+/// Functions shared by all path types, which lets generic code accept any of them.
+///
+/// A typed path defined outside of this crate implements [path_ref](Self::path_ref)
+/// by delegating to a path of this crate that it wraps, so the functions only ever
+/// see a validated path. [FileLike] and [FolderLike] change the path and are only
+/// implemented by the paths of this crate.
+///
+/// ```
+/// use x_path::{AbsoluteFilePath, AnyPathRef, PathLike, RelativeFolderPath};
+///
+/// fn describe<P: PathLike>(path: &P) -> String {
+///     format!("{} segments", path.depth())
+/// }
+///
+/// struct ConfigFile(AbsoluteFilePath);
+///
+/// impl PathLike for ConfigFile {
+///     fn path_ref(&self) -> &AnyPathRef {
+///         self.0.path_ref()
+///     }
+/// }
+///
+/// let file = AbsoluteFilePath::try_from("/dir/file.txt").unwrap();
+/// let folder = RelativeFolderPath::try_from("dir/").unwrap();
+/// assert_eq!(describe(&file), "2 segments");
+/// assert_eq!(describe(&folder), "1 segments");
+/// assert_eq!(describe(&ConfigFile(file)), "2 segments");
+/// ```
+pub trait PathLike {
+    /// The path as any path, ex: with [widen](TypedPathRef::widen).
+    fn path_ref(&self) -> &AnyPathRef;
+
+    fn as_str(&self) -> &str {
+        inner(self).as_str()
+    }
+
+    fn as_path(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+
+    fn segments(&self) -> Segments<'_> {
+        inner(self).segments()
+    }
+
+    /// The number of segments
+    fn depth(&self) -> usize {
+        inner(self).depth()
+    }
+
+    /// True if the last segments of the path are those of `suffix`. Unlike
+    /// `str::ends_with` only whole segments match, and a folder suffix
+    /// only matches a folder.
+    fn ends_with(&self, suffix: &RelativePath) -> bool {
+        inner(self).ends_with_segments(&suffix.0)
+    }
+
+    /// True if the paths are the same when ignoring case, as on Windows where
+    /// `C:\Users\Me\` and `c:\users\me\` are the same folder.
+    fn eq_ignore_case<P: PathLike + ?Sized>(&self, other: &P) -> bool {
+        inner(self).eq_case(inner(other), false)
+    }
+}
+
+/// Functions for the paths that point to a file.
+pub trait FileLike: PathLike + Clone + sealed::PathMut {
+    fn extensions(&self) -> Extensions<'_> {
+        inner(self).extensions()
+    }
+
+    fn extension(&self) -> Option<&str> {
+        inner(self).extension()
+    }
+
+    fn full_extension(&self) -> Option<&str> {
+        inner(self).full_extension()
+    }
+
+    fn with_extension<E: StrValues>(&self, extension: E) -> Self {
+        let mut me = self.clone();
+        me.set_extensions(extension);
+        me
+    }
+
+    fn set_extensions<E: StrValues>(&mut self, extensions: E) {
+        inner_mut(self).set_extensions(extensions);
+    }
+
+    fn file_name(&self) -> &str {
+        inner(self).file_name()
+    }
+
+    fn set_file_name(&mut self, file_name: &str) -> Result<()> {
+        inner_mut(self).set_file_name(file_name)
+    }
+
+    fn with_file_name(&self, file_name: &str) -> Result<Self> {
+        let mut me = self.clone();
+        me.set_file_name(file_name)?;
+        Ok(me)
+    }
+
+    fn file_stem(&self) -> &str {
+        inner(self).file_stem()
+    }
+
+    fn set_file_stem(&mut self, file_stem: &str) -> Result<()> {
+        inner_mut(self).set_file_stem(file_stem)
+    }
+
+    fn with_file_stem(&self, file_stem: &str) -> Result<Self> {
+        let mut me = self.clone();
+        me.set_file_stem(file_stem)?;
+        Ok(me)
+    }
+}
+
+/// Functions for the paths that point to a folder.
+pub trait FolderLike: PathLike + Clone + sealed::PathMut {
+    fn push<S: StrValues>(&mut self, segments: S) -> Result<()> {
+        inner_mut(self).push_segments(segments)
+    }
+
+    fn pushing<S: StrValues>(&self, segments: S) -> Result<Self> {
+        let mut me = self.clone();
+        me.push(segments)?;
+        Ok(me)
+    }
+
+    fn pop(&mut self) {
+        inner_mut(self).pop_last_segment()
+    }
+
+    fn popping(&self) -> Self {
+        let mut me = self.clone();
+        me.pop();
+        me
+    }
+
+    /// Appends a relative folder, use [Join](crate::Join) to get a new path
+    /// of any relative path. Fails if `folder` climbs above the root.
+    fn join(&mut self, folder: &RelativeFolderPath) -> Result<()> {
+        let joined = folder.0.resolve(inner(self))?;
+        *inner_mut(self) = joined;
+        Ok(())
+    }
+}
+
+/// Functions for the paths that start at a root.
+pub trait IsAbsolute: PathLike {
+    /// The folders containing this path, from the nearest one up to the root.
+    /// A folder is not its own ancestor.
+    fn ancestors(&self) -> impl Iterator<Item = AbsoluteFolderPath> {
+        let inner = inner(self);
+        let skip = if inner.is_folder() { 1 } else { 0 };
+        inner
+            .ancestor_folders()
+            .into_iter()
+            .skip(skip)
+            .map(AbsoluteFolderPath::from_inner)
+    }
+
+    /// True if the path is the `folder` or inside it. Unlike `str::starts_with`
+    /// only whole segments match, so `/a/bc` doesn't start with `/a/b/`.
+    fn starts_with(&self, folder: &AbsoluteFolderPath) -> bool {
+        inner(self).starts_with_segments(&folder.0)
+    }
+}
+
+/// Functions for the paths that are relative to a folder.
+pub trait IsRelative: PathLike {
+    /// The absolute type of the same kind, i.e. a file for a file.
+    type Resolved: IsAbsolute;
+
+    /// The number of leading `..` segments, i.e. how many levels
+    /// the path climbs up before descending.
+    fn up_levels(&self) -> usize {
+        inner(self).up_levels()
+    }
+
+    /// True when the path doesn't start with `..`, i.e. it can only
+    /// point to something inside the folder it's resolved against.
+    fn is_descendant_only(&self) -> bool {
+        self.up_levels() == 0
+    }
+
+    /// Resolves the path against the `base` folder, applying any leading `..`
+    /// segments to it. Returns an error if the path climbs above the root.
+    fn resolve(&self, base: &AbsoluteFolderPath) -> Result<Self::Resolved>;
+}
+
+impl<B: Base, K: Kind> PathLike for TypedPath<B, K> {
+    fn path_ref(&self) -> &AnyPathRef {
+        TypedPathRef::from_path_str(&self.0)
+    }
+}
+
+impl<B: Base, K: Kind> PathLike for TypedPathRef<B, K> {
+    fn path_ref(&self) -> &AnyPathRef {
+        TypedPathRef::from_path_str(&self.inner)
+    }
+}
+
+impl<B: Base, K: Kind> sealed::PathMut for TypedPath<B, K> {
+    fn path_string_mut(&mut self, _: sealed::Token) -> &mut PathString {
+        &mut self.0.path
+    }
+}
+
+impl<B: Base> FileLike for TypedPath<B, File> {}
+
+impl<B: Base> FolderLike for TypedPath<B, Folder> {}
+
+impl<K: Kind> IsAbsolute for TypedPath<Abs, K> {}
+
+impl<K: Kind> IsAbsolute for TypedPathRef<Abs, K> {}
+
+impl<K: Kind> IsRelative for TypedPath<Rel, K> {
+    type Resolved = TypedPath<Abs, K>;
+
+    fn resolve(&self, base: &AbsoluteFolderPath) -> Result<TypedPath<Abs, K>> {
+        Ok(TypedPath::from_inner(self.0.resolve(&base.0)?))
+    }
+}
+
+impl<K: Kind> IsRelative for TypedPathRef<Rel, K> {
+    type Resolved = TypedPath<Abs, K>;
+
+    fn resolve(&self, base: &AbsoluteFolderPath) -> Result<TypedPath<Abs, K>> {
+        Ok(TypedPath::from_inner(self.inner.resolve(&base.0)?))
+    }
+}
+
+// The functions of the traits are also inherent, so that callers don't need
+// the traits in scope. An owned path reaches those of TypedPathRef by deref.
+
+impl<B: Base, K: Kind> TypedPathRef<B, K> {
+    pub fn as_str(&self) -> &str {
+        PathLike::as_str(self)
+    }
+
+    pub fn as_path(&self) -> &Path {
+        PathLike::as_path(self)
+    }
+
+    pub fn segments(&self) -> Segments<'_> {
+        PathLike::segments(self)
+    }
+
+    /// The number of segments
+    pub fn depth(&self) -> usize {
+        PathLike::depth(self)
+    }
+
+    /// See [PathLike::ends_with].
+    pub fn ends_with(&self, suffix: &RelativePath) -> bool {
+        PathLike::ends_with(self, suffix)
+    }
+
+    /// See [PathLike::eq_ignore_case].
+    pub fn eq_ignore_case<P: PathLike + ?Sized>(&self, other: &P) -> bool {
+        PathLike::eq_ignore_case(self, other)
+    }
+}
+
+impl<B: Base> TypedPathRef<B, File> {
+    pub fn extensions(&self) -> Extensions<'_> {
+        self.inner.extensions()
+    }
+
+    pub fn full_extension(&self) -> Option<&str> {
+        self.inner.full_extension()
+    }
+}
+
+impl<K: Kind> TypedPathRef<Abs, K> {
+    /// See [IsAbsolute::ancestors].
+    pub fn ancestors(&self) -> impl Iterator<Item = AbsoluteFolderPath> + '_ {
+        IsAbsolute::ancestors(self)
+    }
+
+    /// See [IsAbsolute::starts_with].
+    pub fn starts_with(&self, folder: &AbsoluteFolderPath) -> bool {
+        IsAbsolute::starts_with(self, folder)
+    }
+}
+
+impl<K: Kind> TypedPathRef<Rel, K> {
+    /// See [IsRelative::up_levels].
+    pub fn up_levels(&self) -> usize {
+        IsRelative::up_levels(self)
+    }
+
+    /// See [IsRelative::is_descendant_only].
+    pub fn is_descendant_only(&self) -> bool {
+        IsRelative::is_descendant_only(self)
+    }
+
+    /// See [IsRelative::resolve].
+    pub fn resolve(&self, base: &AbsoluteFolderPath) -> Result<TypedPath<Abs, K>> {
+        IsRelative::resolve(self, base)
+    }
+}
+
+impl<B: Base> TypedPath<B, File> {
+    pub fn with_extension<E: StrValues>(&self, extension: E) -> Self {
+        FileLike::with_extension(self, extension)
+    }
+
+    pub fn set_extensions<E: StrValues>(&mut self, extensions: E) {
+        FileLike::set_extensions(self, extensions)
+    }
+
+    pub fn set_file_name(&mut self, file_name: &str) -> Result<()> {
+        FileLike::set_file_name(self, file_name)
+    }
+
+    pub fn with_file_name(&self, file_name: &str) -> Result<Self> {
+        FileLike::with_file_name(self, file_name)
+    }
+
+    pub fn set_file_stem(&mut self, file_stem: &str) -> Result<()> {
+        FileLike::set_file_stem(self, file_stem)
+    }
+
+    pub fn with_file_stem(&self, file_stem: &str) -> Result<Self> {
+        FileLike::with_file_stem(self, file_stem)
+    }
+}
+
+impl<B: Base> TypedPath<B, Folder> {
+    pub fn push<S: StrValues>(&mut self, segments: S) -> Result<()> {
+        FolderLike::push(self, segments)
+    }
+
+    pub fn pushing<S: StrValues>(&self, segments: S) -> Result<Self> {
+        FolderLike::pushing(self, segments)
+    }
+
+    pub fn pop(&mut self) {
+        FolderLike::pop(self)
+    }
+
+    pub fn popping(&self) -> Self {
+        FolderLike::popping(self)
+    }
+
+    /// See [FolderLike::join].
+    pub fn join(&mut self, folder: &RelativeFolderPath) -> Result<()> {
+        FolderLike::join(self, folder)
+    }
+}
@@ -7,16 +7,31 @@ pub mod absolute_path;
 mod any_file_path;
 mod any_folder_path;
 pub mod any_path;
+mod borrowed;
+mod case;
+mod cmp;
+mod known_folders;
+mod like;
+mod natural;
+mod ops;
 mod relative_file_path;
 mod relative_folder_path;
 mod relative_path;
+mod typed;
 
-pub use absolute_file_path::AbsoluteFilePath;
-pub use absolute_folder_path::AbsoluteFolderPath;
-pub use absolute_path::AbsolutePath;
-pub use any_file_path::AnyFilePath;
-pub use any_folder_path::AnyFolderPath;
-pub use any_path::AnyPath;
-pub use relative_file_path::RelativeFilePath;
-pub use relative_folder_path::RelativeFolderPath;
-pub use relative_path::RelativePath;
+pub use absolute_file_path::{AbsoluteFilePath, AbsoluteFilePathRef};
+pub use absolute_folder_path::{AbsoluteFolderPath, AbsoluteFolderPathRef};
+pub use absolute_path::{AbsolutePath, AbsolutePathRef};
+pub use any_file_path::{AnyFilePath, AnyFilePathRef};
+pub use any_folder_path::{AnyFolderPath, AnyFolderPathRef};
+pub use any_path::{AnyPath, AnyPathRef};
+pub use borrowed::TypedPathRef;
+pub use case::CaseInsensitive;
+pub use known_folders::{KnownFolders, UserDirs};
+pub(crate) use like::inner;
+pub use like::{FileLike, FolderLike, IsAbsolute, IsRelative, PathLike};
+pub use natural::{natural_cmp, natural_cmp_folders_first, NaturalOrd};
+pub use relative_file_path::{RelativeFilePath, RelativeFilePathRef};
+pub use relative_folder_path::{RelativeFolderPath, RelativeFolderPathRef};
+pub use relative_path::{RelativePath, RelativePathRef};
+pub use typed::{marker, TypedPath};
@@ -0,0 +1,87 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use crate::{
+    os::{CurrentOS, OsGroup},
+    path::inner,
+    PathLike,
+};
+
+/// Compares the paths segment by segment with runs of digits compared by their
+/// value, so that `file2.png` comes before `file10.png`. Case is ignored where
+/// the OS ignores it. Use it to sort a listing, ex: `paths.sort_by(natural_cmp)`.
+pub fn natural_cmp<P: PathLike + ?Sized, Q: PathLike + ?Sized>(a: &P, b: &Q) -> Ordering {
+    inner(a).cmp_natural(inner(b), CurrentOS::CASE_SENSITIVE, false)
+}
+
+/// Like [natural_cmp] but the folders in a folder come before its files.
+pub fn natural_cmp_folders_first<P: PathLike + ?Sized, Q: PathLike + ?Sized>(
+    a: &P,
+    b: &Q,
+) -> Ordering {
+    inner(a).cmp_natural(inner(b), CurrentOS::CASE_SENSITIVE, true)
+}
+
+/// Orders the wrapped path with [natural_cmp], or [natural_cmp_folders_first] when
+/// `FOLDERS_FIRST` is true, for use as the key of a `BTreeMap` or in `sort_by_key`.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{NaturalOrd, PathLike, RelativePath};
+///
+/// let mut paths = ["file10.png", "img/", "file2.png"]
+///     .map(|p| RelativePath::try_from(p).map(NaturalOrd::<_, true>))
+///     .into_iter()
+///     .collect::<anyhow::Result<Vec<_>>>()?;
+/// paths.sort();
+/// let names = paths.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+/// # #[cfg(not(windows))]
+/// assert_eq!(names, ["img/", "file2.png", "file10.png"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NaturalOrd<P, const FOLDERS_FIRST: bool = false>(pub P);
+
+impl<P: PathLike, const FOLDERS_FIRST: bool> NaturalOrd<P, FOLDERS_FIRST> {
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+impl<P: PathLike, const FOLDERS_FIRST: bool> PartialEq for NaturalOrd<P, FOLDERS_FIRST> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P: PathLike, const FOLDERS_FIRST: bool> Eq for NaturalOrd<P, FOLDERS_FIRST> {}
+
+impl<P: PathLike, const FOLDERS_FIRST: bool> PartialOrd for NaturalOrd<P, FOLDERS_FIRST> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: PathLike, const FOLDERS_FIRST: bool> Ord for NaturalOrd<P, FOLDERS_FIRST> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        inner(&self.0).cmp_natural(inner(&other.0), CurrentOS::CASE_SENSITIVE, FOLDERS_FIRST)
+    }
+}
+
+impl<P, const FOLDERS_FIRST: bool> Deref for NaturalOrd<P, FOLDERS_FIRST> {
+    type Target = P;
+
+    fn deref(&self) -> &P {
+        &self.0
+    }
+}
+
+impl<P: Display, const FOLDERS_FIRST: bool> Display for NaturalOrd<P, FOLDERS_FIRST> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
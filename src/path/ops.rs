@@ -0,0 +1,125 @@
+use std::ops::{Div, RangeBounds};
+
+use anyhow::Result;
+
+use crate::{
+    path::typed::{Base, File, Folder, Kind, Rel, TypedPath},
+    Join, RelativeFilePath, RelativeFolderPath, RelativePath,
+};
+
+impl<B: Base, K: Kind> TypedPath<B, K> {
+    /// A relative folder with the segments in `range`, ex: `1..3`.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<RelativeFolderPath> {
+        Ok(TypedPath::from_inner(self.0.slice_segments(range)?))
+    }
+
+    /// Replaces the segment at `index`, which must exist. The segment can't be `.`
+    /// or `..`, and only the last of the leading `..` of a relative path can be replaced.
+    pub fn replace_segment(&mut self, index: usize, segment: &str) -> Result<()> {
+        self.0.replace_segment(index, segment)
+    }
+
+    pub fn replacing_segment(&self, index: usize, segment: &str) -> Result<Self> {
+        let mut me = self.clone();
+        me.0.replace_segment(index, segment)?;
+        Ok(me)
+    }
+
+    /// Inserts a segment before the one at `index`, or last when `index`
+    /// is the number of segments. The segment can't be `.` or `..`, nor be
+    /// inserted in front of the leading `..` of a relative path.
+    pub fn insert_segment(&mut self, index: usize, segment: &str) -> Result<()> {
+        self.0.insert_segment(index, segment)
+    }
+
+    pub fn inserting_segment(&self, index: usize, segment: &str) -> Result<Self> {
+        let mut me = self.clone();
+        me.0.insert_segment(index, segment)?;
+        Ok(me)
+    }
+
+    /// Removes the segment at `index`. A file or relative path must keep at least one segment.
+    pub fn remove_segment(&mut self, index: usize) -> Result<()> {
+        self.0.remove_segment(index)
+    }
+
+    pub fn removing_segment(&self, index: usize) -> Result<Self> {
+        let mut me = self.clone();
+        me.0.remove_segment(index)?;
+        Ok(me)
+    }
+
+    /// Splits the path into the folder with the segments before `index`
+    /// and a relative path with the rest.
+    pub fn split_at(&self, index: usize) -> Result<(TypedPath<B, Folder>, RelativePath)> {
+        let (folder, rest) = self.0.split_at_segment(index)?;
+        Ok((TypedPath::from_inner(folder), TypedPath::from_inner(rest)))
+    }
+}
+
+impl<B: Base> TypedPath<B, Folder> {
+    pub fn with_file(&self, file: RelativeFilePath) -> Result<TypedPath<B, File>> {
+        self.joining(&file)
+    }
+
+    pub fn with_file_str(&self, file: &str) -> Result<TypedPath<B, File>> {
+        self.with_file(RelativeFilePath::try_from(file)?)
+    }
+}
+
+impl<B: Base, K: Kind> Join<&TypedPath<Rel, K>> for TypedPath<B, Folder> {
+    type Output = TypedPath<B, K>;
+
+    fn joining(&self, rhs: &TypedPath<Rel, K>) -> Result<TypedPath<B, K>> {
+        Ok(TypedPath::from_inner(rhs.0.resolve(&self.0)?))
+    }
+}
+
+impl<B: Base, K: Kind> Join<TypedPath<Rel, K>> for TypedPath<B, Folder> {
+    type Output = TypedPath<B, K>;
+
+    fn joining(&self, rhs: TypedPath<Rel, K>) -> Result<TypedPath<B, K>> {
+        self.joining(&rhs)
+    }
+}
+
+/// The `/` operator panics if the relative path climbs above the root, use
+/// [Join::joining] to get an error instead.
+fn joined_or_panic<B: Base, K: Kind>(
+    folder: &TypedPath<B, Folder>,
+    rel: &TypedPath<Rel, K>,
+) -> TypedPath<B, K> {
+    folder.joining(rel).unwrap_or_else(|e| panic!("{e}"))
+}
+
+impl<B: Base, K: Kind> Div<&TypedPath<Rel, K>> for &TypedPath<B, Folder> {
+    type Output = TypedPath<B, K>;
+
+    fn div(self, rhs: &TypedPath<Rel, K>) -> TypedPath<B, K> {
+        joined_or_panic(self, rhs)
+    }
+}
+
+impl<B: Base, K: Kind> Div<TypedPath<Rel, K>> for &TypedPath<B, Folder> {
+    type Output = TypedPath<B, K>;
+
+    fn div(self, rhs: TypedPath<Rel, K>) -> TypedPath<B, K> {
+        joined_or_panic(self, &rhs)
+    }
+}
+
+impl<B: Base, K: Kind> Div<&TypedPath<Rel, K>> for TypedPath<B, Folder> {
+    type Output = TypedPath<B, K>;
+
+    fn div(self, rhs: &TypedPath<Rel, K>) -> TypedPath<B, K> {
+        joined_or_panic(&self, rhs)
+    }
+}
+
+impl<B: Base, K: Kind> Div<TypedPath<Rel, K>> for TypedPath<B, Folder> {
+    type Output = TypedPath<B, K>;
+
+    fn div(self, rhs: TypedPath<Rel, K>) -> TypedPath<B, K> {
+        joined_or_panic(&self, &rhs)
+    }
+}
@@ -1,29 +1,18 @@
-use crate::os::CurrentOS;
-use crate::{all_files, AbsoluteFolderPath, RelativeFolderPath};
-use crate::{all_paths, inner::PathInner, try_from};
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{File, Rel, TypedPath};
+use crate::{AbsoluteFilePath, AbsoluteFolderPath};
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct RelativeFilePath(pub(crate) PathInner<CurrentOS>);
-
-all_paths!(RelativeFilePath);
-all_files!(RelativeFilePath);
-try_from!(RelativeFilePath);
+pub type RelativeFilePath = TypedPath<Rel, File>;
+pub type RelativeFilePathRef = TypedPathRef<Rel, File>;
 
 impl RelativeFilePath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_relative()?;
-        self.0.ensure_file()?;
-        Ok(self)
-    }
-
-    pub fn dropping_file(&mut self) -> RelativeFolderPath {
-        RelativeFolderPath(self.0.drop_file())
+    /// Fails if the path climbs above the root.
+    pub fn with_root(&self, root: AbsoluteFolderPath) -> Result<AbsoluteFilePath> {
+        Ok(AbsoluteFilePath::from_inner(self.0.resolve(&root.0)?))
     }
 
-    pub fn with_root(&self, root: AbsoluteFolderPath) -> AbsoluteFolderPath {
-        AbsoluteFolderPath(self.0.with_root(root.as_str()))
+    pub fn with_root_str(&self, root: &str) -> Result<AbsoluteFilePath> {
+        self.with_root(root.try_into()?)
     }
 }
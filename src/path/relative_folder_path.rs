@@ -1,32 +1,18 @@
-use crate::os::CurrentOS;
-use crate::{all_dirs, with_file, AbsoluteFolderPath, RelativeFilePath};
-use crate::{all_paths, inner::PathInner, try_from};
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{Folder, Rel, TypedPath};
+use crate::AbsoluteFolderPath;
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct RelativeFolderPath(pub(crate) PathInner<CurrentOS>);
-
-all_paths!(RelativeFolderPath);
-all_dirs!(RelativeFolderPath);
-try_from!(RelativeFolderPath);
-with_file!(RelativeFolderPath, RelativeFilePath);
+pub type RelativeFolderPath = TypedPath<Rel, Folder>;
+pub type RelativeFolderPathRef = TypedPathRef<Rel, Folder>;
 
 impl RelativeFolderPath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_relative()?;
-        self.0.ensure_folder()?;
-        Ok(self)
-    }
-
-    pub fn with_root(&self, root: AbsoluteFolderPath) -> AbsoluteFolderPath {
-        let path = self.0.path.clone() + &root.0.path;
-        let p = PathInner { path, t: self.0.t };
-        AbsoluteFolderPath(p)
+    /// Fails if the path climbs above the root.
+    pub fn with_root(&self, root: AbsoluteFolderPath) -> Result<AbsoluteFolderPath> {
+        Ok(AbsoluteFolderPath::from_inner(self.0.resolve(&root.0)?))
     }
 
     pub fn with_root_str(&self, root: &str) -> Result<AbsoluteFolderPath> {
-        Ok(self.with_root(root.try_into()?))
+        self.with_root(root.try_into()?)
     }
 }
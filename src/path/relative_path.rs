@@ -1,27 +1,28 @@
-use crate::os::CurrentOS;
-use crate::{all_paths, inner::PathInner, try_from};
+use crate::path::borrowed::TypedPathRef;
+use crate::path::typed::{AnyKind, Rel, TypedPath};
 use crate::{RelativeFilePath, RelativeFolderPath};
-use anyhow::Result;
 use either::Either;
-use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct RelativePath(pub(crate) PathInner<CurrentOS>);
-
-all_paths!(RelativePath);
-try_from!(RelativePath);
+pub type RelativePath = TypedPath<Rel, AnyKind>;
+pub type RelativePathRef = TypedPathRef<Rel, AnyKind>;
 
 impl RelativePath {
-    pub(crate) fn validate(self) -> Result<Self> {
-        self.0.ensure_relative()?;
-        Ok(self)
-    }
-
     pub fn to_concrete(self) -> Either<RelativeFolderPath, RelativeFilePath> {
         match self.0.is_folder() {
-            true => Either::Left(RelativeFolderPath(self.0)),
-            false => Either::Right(RelativeFilePath(self.0)),
+            true => Either::Left(RelativeFolderPath::from_inner(self.0)),
+            false => Either::Right(RelativeFilePath::from_inner(self.0)),
         }
     }
 }
+
+impl From<RelativeFolderPath> for RelativePath {
+    fn from(value: RelativeFolderPath) -> Self {
+        Self::from_inner(value.0)
+    }
+}
+
+impl From<RelativeFilePath> for RelativePath {
+    fn from(value: RelativeFilePath) -> Self {
+        Self::from_inner(value.0)
+    }
+}
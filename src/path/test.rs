@@ -1,4 +1,9 @@
-use crate::{AbsoluteFolderPath, AbsolutePath};
+use crate::path::typed::{Abs, AnyKind, File, Folder, Rel};
+use crate::prelude::*;
+use crate::{
+    AbsoluteFilePath, AbsoluteFilePathRef, AbsoluteFolderPath, AbsoluteFolderPathRef, AbsolutePath,
+    AnyFolderPath, AnyPath, AnyPathRef, RelativeFilePath, RelativeFolderPath, RelativePath,
+};
 
 #[test]
 fn eq_test() {
@@ -17,3 +22,263 @@ fn eq_test() {
         "path is not a folder (it doesn't end with a slash): ./hi"
     );
 }
+
+#[test]
+fn rel_resolve_test() {
+    let base = AbsoluteFolderPath::try_from("/base/dir/").unwrap();
+
+    let rel = RelativeFolderPath::try_from("../../up/").unwrap();
+    assert_eq!(rel.up_levels(), 2);
+    assert!(!rel.is_descendant_only());
+    assert!(rel.resolve(&base).unwrap() == "/up/");
+
+    let rel = RelativeFilePath::try_from("down/file.txt").unwrap();
+    assert_eq!(rel.up_levels(), 0);
+    assert!(rel.is_descendant_only());
+    let file: AbsoluteFilePath = rel.resolve(&base).unwrap();
+    assert_eq!(file.as_str(), "/base/dir/down/file.txt");
+
+    let rel = RelativePath::try_from("../../../").unwrap();
+    assert!(rel.resolve(&base).is_err());
+}
+
+#[test]
+fn ancestry_test() {
+    let dir = AbsoluteFolderPath::try_from("/a/b/").unwrap();
+    let file = AbsoluteFilePath::try_from("/a/b/c/d.txt").unwrap();
+    let partial = AbsoluteFilePath::try_from("/a/bc/d.txt").unwrap();
+
+    let ancestors = file.ancestors().map(|a| a.as_str().to_string());
+    assert_eq!(
+        ancestors.collect::<Vec<_>>(),
+        vec!["/a/b/c/", "/a/b/", "/a/", "/"]
+    );
+    assert_eq!(dir.ancestors().next().unwrap().as_str(), "/a/");
+
+    assert!(file.starts_with(&dir));
+    assert!(dir.starts_with(&dir));
+    assert!(!partial.starts_with(&dir));
+    assert!(partial
+        .as_str()
+        .starts_with(dir.as_str().trim_end_matches('/')));
+
+    assert!(dir.is_ancestor_of(&file));
+    assert!(!dir.is_ancestor_of(&dir));
+    assert!(!dir.is_ancestor_of(&partial));
+
+    assert_eq!(file.depth(), 4);
+    assert_eq!(AbsoluteFolderPath::try_from("/").unwrap().depth(), 0);
+
+    let suffix = RelativePath::try_from("c/d.txt").unwrap();
+    assert!(file.ends_with(&suffix));
+    assert!(!file.ends_with(&RelativePath::try_from("b/d.txt").unwrap()));
+    assert!(!file.ends_with(&RelativePath::try_from("d.txt/").unwrap()));
+    assert!(!partial.ends_with(&RelativePath::try_from("c/d.txt").unwrap()));
+    assert!(dir.ends_with(&RelativePath::try_from("b/").unwrap()));
+
+    let common = AbsoluteFolderPath::common_ancestor(&[
+        file.clone().into(),
+        partial.into(),
+        AbsolutePath::try_from("/a/b/").unwrap(),
+    ]);
+    assert_eq!(common.unwrap().as_str(), "/a/");
+    let common = AbsoluteFolderPath::common_ancestor(&[file.into(), dir.clone().into()]);
+    assert_eq!(common.unwrap().as_str(), "/a/b/");
+    assert_eq!(AbsoluteFolderPath::common_ancestor(&[]), None);
+}
+
+#[test]
+fn segment_edit_test() {
+    let file = AbsoluteFilePath::try_from("/out/debug/app/main.js").unwrap();
+
+    let release = file.replacing_segment(1, "release").unwrap();
+    assert_eq!(release.as_str(), "/out/release/app/main.js");
+    assert_eq!(file.slice(1..3).unwrap().as_str(), "debug/app/");
+
+    let (folder, rest) = file.split_at(2).unwrap();
+    assert_eq!(folder.as_str(), "/out/debug/");
+    assert_eq!(rest.as_str(), "app/main.js");
+
+    let mut dir = RelativeFolderPath::try_from("a/c/").unwrap();
+    dir.insert_segment(1, "b").unwrap();
+    assert_eq!(dir.as_str(), "a/b/c/");
+    assert_eq!(dir.removing_segment(0).unwrap().as_str(), "b/c/");
+    assert!(dir.insert_segment(4, "x").is_err());
+}
+
+#[test]
+fn join_test() {
+    let abs = AbsoluteFolderPath::try_from("/prj/").unwrap();
+    let rel = RelativeFolderPath::try_from("src/").unwrap();
+    let file = RelativeFilePath::try_from("main.rs").unwrap();
+
+    let joined: AbsoluteFilePath = &abs / &rel / &file;
+    assert_eq!(joined.as_str(), "/prj/src/main.rs");
+
+    let joined: RelativeFilePath = rel.joining(&file).unwrap();
+    assert_eq!(joined.as_str(), "src/main.rs");
+
+    let any: AnyPath = AnyFolderPath::from(abs.clone()) / RelativePath::try_from("a/").unwrap();
+    assert_eq!(any.as_str(), "/prj/a/");
+
+    assert_eq!(
+        file.with_root(abs.clone()).unwrap().as_str(),
+        "/prj/main.rs"
+    );
+    assert_eq!(rel.with_root(abs.clone()).unwrap().as_str(), "/prj/src/");
+
+    let up = RelativeFolderPath::try_from("../../").unwrap();
+    assert_eq!(
+        abs.joining(&up).unwrap_err().to_string(),
+        "path climbs above the root: ../../ (resolved against /prj/)"
+    );
+    assert!(up.with_root(abs.clone()).is_err());
+
+    let back = RelativeFolderPath::try_from("../").unwrap();
+    let empty = rel.joining(&back).unwrap();
+    assert_eq!(empty.as_str(), "");
+    assert_eq!(&abs / &empty, abs);
+    assert_eq!(
+        abs.with_file_str("a/b.txt").unwrap().as_str(),
+        "/prj/a/b.txt"
+    );
+    assert!(abs.with_file_str("/a/b.txt").is_err());
+
+    let mut dir = abs;
+    dir.join(&RelativeFolderPath::try_from("../other/").unwrap())
+        .unwrap();
+    assert_eq!(dir.as_str(), "/other/");
+    assert!(dir.join(&up).is_err());
+    assert_eq!(dir.as_str(), "/other/");
+}
+
+#[test]
+fn narrow_widen_test() {
+    let any = AnyPath::try_from("/dir/file.txt").unwrap();
+    let file: AbsoluteFilePath = any.clone().narrow().unwrap();
+    assert_eq!(file.as_str(), "/dir/file.txt");
+
+    let err = any.clone().narrow::<Rel, File>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "path is not relative (it should not start with a slash): /dir/file.txt"
+    );
+
+    let any = any.try_narrow::<Abs, Folder>().unwrap_err();
+    assert_eq!(any.as_str(), "/dir/file.txt");
+
+    let abs: AbsolutePath = file.clone().widen();
+    let any: AnyPath = file.widen();
+    assert_eq!(abs.as_str(), any.as_str());
+
+    assert_eq!(format!("{any:?}"), "AnyPath(/dir/file.txt)");
+    assert_eq!(format!("{abs:?}"), "AbsolutePath(/dir/file.txt)");
+    let dir = RelativeFolderPath::try_from("a/").unwrap();
+    assert_eq!(format!("{dir:?}"), "RelativeFolderPath(a/)");
+}
+
+#[test]
+fn borrowed_test() {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    let file = AbsoluteFilePath::try_from("/a/b/c.txt").unwrap();
+    let folder: &AbsoluteFolderPathRef = file.dropping_file();
+    assert_eq!(folder.as_str(), "/a/b/");
+    assert_eq!(file.file_name(), "c.txt");
+    assert_eq!(folder.leading(1).unwrap().as_str(), "/a/");
+    assert_eq!(folder.leading(0).unwrap().as_str(), "/");
+    assert!(folder.leading(3).is_none());
+
+    let parent = folder.parent().unwrap();
+    assert!(matches!(parent, Cow::Borrowed(_)));
+    assert_eq!(parent.as_str(), "/a/");
+
+    let up = RelativeFolderPath::try_from("../").unwrap();
+    let parent = up.parent().unwrap();
+    assert!(matches!(parent, Cow::Owned(_)));
+    assert_eq!(parent.as_str(), "../../");
+
+    let file_ref: &AbsoluteFilePathRef = &file;
+    let any: &AnyPathRef = file_ref.widen();
+    let narrowed: &AbsoluteFilePathRef = any.narrow().unwrap();
+    assert_eq!(*narrowed, file);
+    assert!(any.narrow::<Rel, AnyKind>().is_err());
+    assert_eq!(format!("{narrowed:?}"), "AbsoluteFilePathRef(/a/b/c.txt)");
+
+    let mut map = HashMap::new();
+    map.insert(file.clone(), 1);
+    assert_eq!(map.get(narrowed), Some(&1));
+    assert_eq!(narrowed.to_owned(), file);
+}
+
+#[test]
+fn hash_ord_test() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let mut paths = ["/a-b/", "/a/b", "/a/", "/a/b/", "/"]
+        .map(|p| AnyPath::try_from(p).unwrap())
+        .to_vec();
+    paths.sort();
+    let sorted = paths.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+    assert_eq!(sorted, vec!["/", "/a/", "/a/b", "/a/b/", "/a-b/"]);
+
+    let rel = AnyPath::try_from("z").unwrap();
+    assert!(rel < paths[0]);
+
+    let files = ["/a/./b.txt", "/a/b.txt", "/a/c.txt"]
+        .map(|p| AbsoluteFilePath::try_from(p).unwrap())
+        .to_vec();
+    assert_eq!(files[0], files[1]);
+    assert_eq!(files.iter().collect::<HashSet<_>>().len(), 2);
+    assert_eq!(files.iter().collect::<BTreeSet<_>>().len(), 2);
+    assert!(files[0] == "/a/b.txt" && files[0] == "/a/./b.txt");
+
+    let borrowed: &AbsoluteFilePathRef = &files[2];
+    assert!(*borrowed > *files[0]);
+}
+
+#[test]
+fn case_test() {
+    use crate::CaseInsensitive;
+    use std::collections::BTreeSet;
+
+    let upper = AbsoluteFolderPath::try_from("/Users/Me/").unwrap();
+    let lower = AbsoluteFolderPath::try_from("/users/me/").unwrap();
+    assert_ne!(upper, lower);
+    assert!(upper.eq_ignore_case(&lower));
+    assert!(upper.eq_ignore_case(&*lower));
+    assert!(!upper.eq_ignore_case(&AbsoluteFolderPath::try_from("/users/you/").unwrap()));
+
+    let set = [upper.clone(), lower]
+        .into_iter()
+        .map(CaseInsensitive)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(set.len(), 1);
+    assert!(set.contains(&CaseInsensitive(upper)));
+}
+
+#[test]
+fn cross_type_eq_test() {
+    let folder = AbsoluteFolderPath::try_from("/a/b/").unwrap();
+    let any_folder = AnyFolderPath::from(folder.clone());
+    let any = AnyPath::try_from("/a/b/").unwrap();
+    let abs = AbsolutePath::from(folder.clone());
+
+    assert_eq!(folder, any_folder);
+    assert_eq!(any_folder, folder);
+    assert_eq!(any, abs);
+    assert_eq!(abs, any);
+    assert_eq!(any, folder);
+    assert_eq!(*folder, any);
+
+    let other = AbsolutePath::try_from("/a/c/").unwrap();
+    assert!(any < other && folder < other);
+
+    let file = AnyPath::try_from("/a/b").unwrap();
+    assert!(file != folder);
+
+    assert!(any == "/a/./b/" && "/a/b/" == any);
+    assert!(AbsolutePath::try_from("./hi").unwrap() == "./hi");
+    assert!(any != "a/b/");
+}
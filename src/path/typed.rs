@@ -0,0 +1,230 @@
+use std::marker::PhantomData;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    inner::{PathInner, PathStr},
+    os::CurrentOS,
+};
+
+pub use marker::{Abs, AnyBase, AnyKind, Base, File, Folder, Kind, Rel};
+
+/// The type states of [TypedPath]: where a path starts and what it points to.
+pub mod marker {
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// Where a path starts: at a root ([Abs]), at some folder ([Rel]) or either ([AnyBase]).
+    pub trait Base: sealed::Sealed + Overlaps<Self> {
+        #[doc(hidden)]
+        const NAME: &'static str;
+        /// Some(true) if absolute, Some(false) if relative and None if either.
+        #[doc(hidden)]
+        const ABSOLUTE: Option<bool>;
+    }
+
+    /// What a path points to: a [File], a [Folder] or either ([AnyKind]).
+    pub trait Kind: sealed::Sealed + Overlaps<Self> {
+        #[doc(hidden)]
+        const NAME: &'static str;
+        /// Some(true) if a folder, Some(false) if a file and None if either.
+        #[doc(hidden)]
+        const FOLDER: Option<bool>;
+    }
+
+    /// The narrower marker can be widened to `Wide` without checks, ex: [Abs] to [AnyBase].
+    pub trait Within<Wide>: sealed::Sealed {}
+
+    /// A path can be of both types, ex: [Abs] and [AnyBase] but not [Abs] and [Rel],
+    /// which is what makes paths of the two types comparable.
+    pub trait Overlaps<Other: ?Sized>: sealed::Sealed {}
+
+    macro_rules! marker {
+        ($(#[$doc:meta])* $name:ident, $trait:ident, $const:ident, $value:expr, $text:literal) => {
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum $name {}
+
+            impl sealed::Sealed for $name {}
+
+            impl $trait for $name {
+                const NAME: &'static str = $text;
+                const $const: Option<bool> = $value;
+            }
+
+            impl Within<$name> for $name {}
+            impl Overlaps<$name> for $name {}
+        };
+    }
+
+    marker!(
+        /// Absolute, starts at a root
+        Abs, Base, ABSOLUTE, Some(true), "Absolute"
+    );
+    marker!(
+        /// Relative to some folder
+        Rel, Base, ABSOLUTE, Some(false), "Relative"
+    );
+    marker!(
+        /// Absolute or relative
+        AnyBase, Base, ABSOLUTE, None, "Any"
+    );
+    marker!(
+        /// A file, doesn't end with a slash
+        File, Kind, FOLDER, Some(false), "File"
+    );
+    marker!(
+        /// A folder, ends with a slash
+        Folder, Kind, FOLDER, Some(true), "Folder"
+    );
+    marker!(
+        /// A file or a folder
+        AnyKind, Kind, FOLDER, None, ""
+    );
+
+    impl Within<AnyBase> for Abs {}
+    impl Within<AnyBase> for Rel {}
+    impl Within<AnyKind> for File {}
+    impl Within<AnyKind> for Folder {}
+
+    impl Overlaps<AnyBase> for Abs {}
+    impl Overlaps<AnyBase> for Rel {}
+    impl Overlaps<Abs> for AnyBase {}
+    impl Overlaps<Rel> for AnyBase {}
+    impl Overlaps<AnyKind> for File {}
+    impl Overlaps<AnyKind> for Folder {}
+    impl Overlaps<File> for AnyKind {}
+    impl Overlaps<Folder> for AnyKind {}
+}
+
+/// A path that is known to be absolute or relative ([Base]) and to point to a file
+/// or a folder ([Kind]). The nine combinations have names, ex: [AbsoluteFilePath](crate::AbsoluteFilePath)
+/// is `TypedPath<Abs, File>`.
+///
+/// Paths are equal and hash the same when their normalised strings are, so `a/./b` equals
+/// `a/b`. On Windows case is ignored, elsewhere [CaseInsensitive](crate::CaseInsensitive) opts in.
+/// They are ordered by root and then segment by segment, i.e. `/a/b` comes before `/a-b/`,
+/// which makes them usable as keys of a `HashMap` or `BTreeMap` and sortable.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{AbsoluteFilePath, AnyPath, PathLike};
+///
+/// let file = AbsoluteFilePath::try_from("/dir/file.txt")?;
+/// let any: AnyPath = file.widen();
+/// let file: AbsoluteFilePath = any.narrow()?;
+/// # #[cfg(not(windows))]
+/// assert_eq!(file.as_str(), "/dir/file.txt");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize)]
+#[serde(transparent, bound = "")]
+#[repr(transparent)]
+pub struct TypedPath<B: Base, K: Kind>(
+    pub(crate) PathInner<CurrentOS>,
+    #[serde(skip)] PhantomData<(B, K)>,
+);
+
+/// Checks that the path is of the type given by the markers.
+pub(crate) fn check<B: Base, K: Kind>(path: &PathStr<CurrentOS>) -> Result<()> {
+    match B::ABSOLUTE {
+        Some(true) => path.ensure_absolute()?,
+        Some(false) => path.ensure_relative()?,
+        None => {}
+    }
+    match K::FOLDER {
+        Some(true) => path.ensure_folder()?,
+        Some(false) => path.ensure_file()?,
+        None => {}
+    }
+    Ok(())
+}
+
+impl<B: Base, K: Kind> TypedPath<B, K> {
+    pub(crate) fn from_inner(inner: PathInner<CurrentOS>) -> Self {
+        Self(inner, PhantomData)
+    }
+
+    pub(crate) fn validate(self) -> Result<Self> {
+        check::<B, K>(&self.0)?;
+        Ok(self)
+    }
+
+    /// Converts to a wider type without any checks, ex: an absolute file to any path.
+    pub fn widen<B2: Base, K2: Kind>(self) -> TypedPath<B2, K2>
+    where
+        B: marker::Within<B2>,
+        K: marker::Within<K2>,
+    {
+        TypedPath::from_inner(self.0)
+    }
+
+    /// Converts to any other type, which fails if the path isn't of that type,
+    /// ex: any path that is a relative folder to a relative folder.
+    pub fn narrow<B2: Base, K2: Kind>(self) -> Result<TypedPath<B2, K2>> {
+        TypedPath::from_inner(self.0).validate()
+    }
+
+    /// Like [narrow](Self::narrow) but keeps the path when it's not of the type.
+    pub fn try_narrow<B2: Base, K2: Kind>(self) -> std::result::Result<TypedPath<B2, K2>, Self> {
+        match TypedPath::<B2, K2>::from_inner(self.0.clone()).validate() {
+            Ok(narrowed) => Ok(narrowed),
+            Err(_) => Err(self),
+        }
+    }
+}
+
+impl<B: Base, K: Kind> Clone for TypedPath<B, K> {
+    fn clone(&self) -> Self {
+        Self::from_inner(self.0.clone())
+    }
+}
+
+impl<B: Base, K: Kind> TryFrom<String> for TypedPath<B, K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::from_inner(PathInner::new(&value)?).validate()
+    }
+}
+
+impl<B: Base, K: Kind> TryFrom<&str> for TypedPath<B, K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::from_inner(PathInner::new(value)?).validate()
+    }
+}
+
+impl<B: Base, K: Kind> TryFrom<std::path::PathBuf> for TypedPath<B, K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: std::path::PathBuf) -> Result<Self> {
+        Self::from_inner(PathInner::new_from_path(&value)?).validate()
+    }
+}
+
+impl<B: Base, K: Kind> AsRef<std::path::Path> for TypedPath<B, K> {
+    fn as_ref(&self) -> &std::path::Path {
+        self.0.as_path()
+    }
+}
+
+impl<B: Base, K: Kind> std::fmt::Display for TypedPath<B, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<B: Base, K: Kind> std::fmt::Debug for TypedPath<B, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}{}Path({:#?})", B::NAME, K::NAME, self.0)
+        } else {
+            write!(f, "{}{}Path({:?})", B::NAME, K::NAME, self.0)
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::{ensure, Result};
+#[cfg(feature = "redact-json")]
+use serde::Serialize;
+#[cfg(feature = "redact-json")]
+use serde_json::Value;
+
+use crate::{
+    fs::os_temp_folder,
+    os::{self, CurrentOS, OsGroup},
+    AbsoluteFolderPath,
+};
+
+/// Rewrites machine specific folders in text, such as snapshots, to stable
+/// placeholders: `/home/me/prj/src/main.rs` becomes `[HOME]/prj/src/main.rs`.
+///
+/// Only whole segments are replaced and when roots are nested the longest one
+/// wins, so a project folder inside home is redacted as the project folder.
+/// With the feature `redact-json`, serialisable values are redacted string by string
+/// with `redact_value`, and in JSON text the escaped `\\` separators of windows are
+/// also recognised.
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use x_path::{AbsoluteFolderPath, Redactions};
+///
+/// let prj = AbsoluteFolderPath::try_from("/work/prj/")?;
+/// let redactions = Redactions::new().with_root(&prj, "PRJ")?;
+///
+/// #[cfg(not(windows))]
+/// assert_eq!(redactions.redact("error in /work/prj/src/lib.rs"), "error in [PRJ]/src/lib.rs");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Redactions {
+    roots: Vec<(String, String)>,
+}
+
+impl Redactions {
+    /// No roots registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The user home as `[HOME]`, the current working directory as `[CWD]` and
+    /// the OS temp folder as `[TMP]`.
+    pub fn standard() -> Result<Self> {
+        let mut me = Self::new();
+        me.push(CurrentOS::home()?, "HOME")?;
+        me.push(CurrentOS::current()?, "CWD")?;
+        let tmp = os_temp_folder()?;
+        if let Ok(canonical) = tmp.canonicalize() {
+            if canonical != tmp {
+                me.push(canonical.0.path.into_string(), "TMP")?;
+            }
+        }
+        me.push(tmp.0.path.into_string(), "TMP")?;
+        Ok(me)
+    }
+
+    /// Adds a root that is replaced by `[<name>]`.
+    pub fn with_root(mut self, root: &AbsoluteFolderPath, name: &str) -> Result<Self> {
+        self.push(root.0.path.to_string(), name)?;
+        Ok(self)
+    }
+
+    fn push(&mut self, root: String, name: &str) -> Result<()> {
+        ensure!(
+            !name.is_empty() && !name.contains(['[', ']']),
+            "invalid redaction name '{name}' for {root}"
+        );
+        self.roots.push((root, format!("[{name}]")));
+        Ok(())
+    }
+
+    pub fn redact(&self, text: &str) -> String {
+        os::redact::<CurrentOS>(text, &self.roots)
+    }
+
+    /// Serialises the value to JSON and redacts every string in it, including the
+    /// keys of maps, ex: for a snapshot of a struct holding paths.
+    #[cfg(feature = "redact-json")]
+    pub fn redact_value<T: Serialize>(&self, value: &T) -> Result<Value> {
+        let mut value = serde_json::to_value(value)?;
+        self.redact_leaves(&mut value);
+        Ok(value)
+    }
+
+    #[cfg(feature = "redact-json")]
+    fn redact_leaves(&self, value: &mut Value) {
+        match value {
+            Value::String(s) => *s = self.redact(s),
+            Value::Array(values) => values.iter_mut().for_each(|v| self.redact_leaves(v)),
+            Value::Object(map) => {
+                *map = std::mem::take(map)
+                    .into_iter()
+                    .map(|(k, mut v)| {
+                        self.redact_leaves(&mut v);
+                        (self.redact(&k), v)
+                    })
+                    .collect();
+            }
+            Value::Null | Value::Bool(_) | Value::Number(_) => {}
+        }
+    }
+}
+
+/// Redacts `text` with the [standard](Redactions::standard) roots.
+pub fn redact(text: &str) -> Result<String> {
+    Ok(Redactions::standard()?.redact(text))
+}
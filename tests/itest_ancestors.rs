@@ -0,0 +1,73 @@
+use x_path::{tree, AbsoluteFolderPath, Cascade, StopAt, TempFolder};
+
+fn fixture() -> (TempFolder, AbsoluteFolderPath) {
+    let tmp = TempFolder::new().unwrap();
+    tree! {
+        "app.toml" => "level = 0",
+        "prj/" => {
+            ".git/" => {},
+            "Cargo.toml" => "",
+            "app.toml" => "level = 1",
+            "crates/" => {
+                "core/" => {
+                    "app.toml" => "level = 2",
+                    "src/" => {},
+                },
+            },
+        },
+    }
+    .create_in(&tmp)
+    .unwrap();
+    let start =
+        AbsoluteFolderPath::try_from(format!("{:#}prj/crates/core/src/", tmp.path())).unwrap();
+    (tmp, start)
+}
+
+#[test]
+fn find_ancestor() {
+    let (tmp, start) = fixture();
+    let prj = format!("{:#}prj/", tmp.path());
+
+    let (folder, marker) = start.find_ancestor_with(&["Cargo.toml"]).unwrap();
+    assert_eq!(folder.as_str(), prj);
+    assert_eq!(marker.as_str(), format!("{prj}Cargo.toml"));
+
+    let (folder, marker) = start.find_ancestor_with(&[".git/"]).unwrap();
+    assert_eq!(folder.as_str(), prj);
+    assert_eq!(marker.as_str(), format!("{prj}.git/"));
+
+    // a folder named like a file marker doesn't match
+    assert!(start.find_ancestor_with(&["crates"]).is_none());
+
+    let (folder, _) = start.find_ancestor_with(&["app.toml"]).unwrap();
+    assert!(folder.as_str().ends_with("core/"));
+}
+
+#[test]
+fn stop_at_depth() {
+    let (_tmp, start) = fixture();
+    let stop = StopAt::default().max_depth(2);
+    assert!(start
+        .find_ancestor_with_stop(&["Cargo.toml"], &stop)
+        .is_none());
+    let stop = StopAt::default().max_depth(3);
+    assert!(start
+        .find_ancestor_with_stop(&["Cargo.toml"], &stop)
+        .is_some());
+}
+
+#[test]
+fn cascade() {
+    let (tmp, start) = fixture();
+    let stop = StopAt::default().max_depth(4);
+    let cascade = Cascade::find(&start, &["app.toml"], &stop).unwrap();
+    let levels = cascade
+        .root_first()
+        .map(|f| f.read_to_string().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(levels, vec!["level = 0", "level = 1", "level = 2"]);
+    assert_eq!(
+        cascade.nearest_first().next().unwrap().as_str(),
+        format!("{:#}prj/crates/core/app.toml", tmp.path())
+    );
+}
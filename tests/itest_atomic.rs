@@ -0,0 +1,63 @@
+use std::{fs, io::Write};
+
+use x_path::{AbsoluteFilePath, AbsoluteFolderPath, TempFolder};
+
+fn file_count(dir: &AbsoluteFolderPath) -> usize {
+    dir.read_dir().unwrap().len()
+}
+
+#[test]
+fn write_atomic() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+    let file = AbsoluteFilePath::try_from(format!("{dir:#}config.toml")).unwrap();
+
+    file.write_atomic("first").unwrap();
+    assert_eq!(file.read_to_string().unwrap(), "first");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    let backup = file.write_atomic_with_backup("second").unwrap().unwrap();
+    assert_eq!(file.read_to_string().unwrap(), "second");
+    assert_eq!(backup.file_name(), "config.toml.bak");
+    assert_eq!(backup.read_to_string().unwrap(), "first");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&file).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // only the file and its backup, no temp files
+    assert_eq!(file_count(dir), 2);
+}
+
+#[test]
+fn atomic_writer() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+    let file = AbsoluteFilePath::try_from(format!("{dir:#}large.txt")).unwrap();
+    file.write("old").unwrap();
+
+    {
+        let mut writer = file.atomic_writer().unwrap();
+        writer.write_all(b"never committed").unwrap();
+        assert_eq!(file_count(dir), 2);
+    }
+    // dropping without commit removes the temp file
+    assert_eq!(file.read_to_string().unwrap(), "old");
+    assert_eq!(file_count(dir), 1);
+
+    let mut writer = file.atomic_writer().unwrap();
+    for i in 0..1000 {
+        writeln!(writer, "line {i}").unwrap();
+    }
+    writer.commit().unwrap();
+    assert_eq!(file.read_to_string().unwrap().lines().count(), 1000);
+    assert_eq!(file_count(dir), 1);
+}
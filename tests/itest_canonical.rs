@@ -0,0 +1,57 @@
+use std::fs;
+
+use x_path::{AbsoluteFilePath, AbsoluteFolderPath, AbsolutePath, KindChanged, TempFolder};
+
+#[cfg(unix)]
+#[test]
+fn canonicalize_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.canonicalize().unwrap();
+    fs::create_dir_all(format!("{dir}target/sub")).unwrap();
+    fs::write(format!("{dir}target/file.txt"), "hi").unwrap();
+    symlink(format!("{dir}target"), format!("{dir}link")).unwrap();
+
+    // `link/../b` is normalised to `b` when the path is created, so `.canonicalize`
+    // only resolves links that aren't followed by a `..`, see `canonicalize_str`
+    let through_link = AbsoluteFolderPath::try_from(format!("{dir}link/sub/")).unwrap();
+    let canonical = through_link.canonicalize().unwrap();
+    assert_eq!(canonical.as_str(), format!("{dir}target/sub/"));
+    assert!(through_link.is_same_file(&canonical).unwrap());
+
+    let up = AbsoluteFolderPath::try_from(format!("{dir}link/../sub/")).unwrap();
+    assert_eq!(up.as_str(), format!("{dir}sub/"));
+
+    // as written, the OS goes up from the target of the link
+    fs::create_dir_all(format!("{dir}target/inner")).unwrap();
+    symlink(format!("{dir}target/inner"), format!("{dir}inner-link")).unwrap();
+    let up = AbsolutePath::canonicalize_str(&format!("{dir}inner-link/../sub/")).unwrap();
+    assert_eq!(up.as_str(), format!("{dir}target/sub/"));
+    let err = AbsolutePath::canonicalize_str(&format!("{dir}link/../sub/")).unwrap_err();
+    assert!(err.to_string().starts_with("could not canonicalize path: "));
+
+    let file = AbsoluteFilePath::try_from(format!("{dir}link/file.txt")).unwrap();
+    let canonical = file.canonicalize().unwrap();
+    assert_eq!(canonical.as_str(), format!("{dir}target/file.txt"));
+    assert!(file.is_same_file(&canonical).unwrap());
+
+    let other = AbsoluteFilePath::try_from(format!("{dir}other.txt")).unwrap();
+    fs::write(&other, "hi").unwrap();
+    assert!(!file.is_same_file(&other).unwrap());
+
+    // a file path that is a symlink to a folder
+    let as_file = AbsoluteFilePath::try_from(format!("{dir}link")).unwrap();
+    let err = as_file.canonicalize().unwrap_err();
+    let changed = err.downcast_ref::<KindChanged>().unwrap();
+    assert_eq!(changed.resolved.as_str(), format!("{dir}target/"));
+}
+
+#[test]
+fn canonicalize_missing() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+    let missing = AbsoluteFilePath::try_from(format!("{dir}missing.txt")).unwrap();
+    let err = missing.canonicalize().unwrap_err().to_string();
+    assert!(err.starts_with("could not canonicalize path: "));
+}
@@ -0,0 +1,17 @@
+use x_path::{AbsolutePath, TempFolder};
+
+#[test]
+fn eq_str_follows_the_current_folder() {
+    let (first, second) = (TempFolder::new().unwrap(), TempFolder::new().unwrap());
+
+    std::env::set_current_dir(&*first).unwrap();
+    let in_first = AbsolutePath::try_from("./x").unwrap();
+    assert!(in_first == "./x");
+    assert!(in_first == in_first.as_str());
+
+    std::env::set_current_dir(&*second).unwrap();
+    let in_second = AbsolutePath::try_from("./x").unwrap();
+    assert!(in_second == "./x");
+    assert!(in_first != "./x");
+    assert!(in_first == in_first.as_str());
+}
@@ -0,0 +1,50 @@
+use either::Either;
+use x_path::{AbsoluteFilePath, AbsoluteFolderPath, AbsolutePath, TryFromFs};
+
+#[test]
+fn folder_from_fs() {
+    // the trailing slash is added for existing folders
+    let p = AbsoluteFolderPath::try_from_fs("./src").unwrap();
+    assert!(p.as_str().ends_with(std::path::MAIN_SEPARATOR));
+    assert!(p == "./src/");
+
+    let p = AbsoluteFolderPath::try_from_fs("./doesntexist/").unwrap();
+    assert!(p == "./doesntexist/");
+
+    let err = AbsoluteFolderPath::try_from_fs("./Cargo.toml").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("path is a file, not a folder: "));
+
+    let err = AbsoluteFolderPath::try_from_fs("./doesntexist").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("path is not an existing folder and doesn't end with a slash: "));
+}
+
+#[test]
+fn file_from_fs() {
+    let p = AbsoluteFilePath::try_from_fs("./Cargo.toml").unwrap();
+    assert_eq!(p.file_name(), "Cargo.toml");
+
+    let p = AbsoluteFilePath::try_from_fs("./doesntexist.txt").unwrap();
+    assert_eq!(p.file_name(), "doesntexist.txt");
+
+    let err = AbsoluteFilePath::try_from_fs("./src").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("path is a folder, not a file: "));
+}
+
+#[test]
+fn concrete_from_fs() {
+    let p = AbsolutePath::try_from_fs("./src").unwrap();
+    assert!(p == "./src/");
+
+    let p = AbsolutePath::try_from("./src").unwrap();
+    assert!(matches!(p.clone().to_concrete(), Either::Right(_)));
+    assert!(matches!(p.to_concrete_fs(), Either::Left(_)));
+
+    let p = AbsolutePath::try_from("./Cargo.toml").unwrap();
+    assert!(matches!(p.to_concrete_fs(), Either::Right(_)));
+}
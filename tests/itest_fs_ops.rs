@@ -0,0 +1,142 @@
+use x_path::{AbsoluteFilePath, AbsoluteFolderPath, TempFolder};
+
+fn file(path: String) -> AbsoluteFilePath {
+    AbsoluteFilePath::try_from(path).unwrap()
+}
+
+fn folder(path: String) -> AbsoluteFolderPath {
+    AbsoluteFolderPath::try_from(path).unwrap()
+}
+
+#[test]
+fn file_ops() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+    let sub = folder(format!("{dir:#}sub/"));
+    sub.create_all().unwrap();
+
+    let f = file(format!("{dir:#}file.txt"));
+    f.write("hello").unwrap();
+    assert_eq!(f.read_to_string().unwrap(), "hello");
+    assert_eq!(f.read().unwrap(), b"hello");
+
+    let copy = f.copy_to(&sub).unwrap();
+    assert_eq!(copy.as_str(), format!("{sub:#}file.txt"));
+    assert_eq!(copy.read_to_string().unwrap(), "hello");
+
+    let renamed = copy.rename_to("renamed.txt").unwrap();
+    assert!(!copy.exists());
+    assert!(renamed.exists());
+
+    let moved = renamed.move_to(dir).unwrap();
+    assert_eq!(moved.as_str(), format!("{dir:#}renamed.txt"));
+    assert!(!renamed.exists());
+
+    moved.remove().unwrap();
+    assert!(!moved.exists());
+
+    let err = moved.read_to_string().unwrap_err().to_string();
+    assert!(err.starts_with("could not read file: "), "{err}");
+}
+
+#[test]
+fn folder_ops() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+    let src = folder(format!("{dir:#}src/"));
+    folder(format!("{src:#}a/b/")).create_all().unwrap();
+    file(format!("{src:#}a/b/deep.txt")).write("deep").unwrap();
+    file(format!("{src:#}top.txt")).write("top").unwrap();
+
+    let mut entries = src
+        .read_dir()
+        .unwrap()
+        .into_iter()
+        .map(|p| p.as_str().to_string())
+        .collect::<Vec<_>>();
+    entries.sort();
+    assert_eq!(
+        entries,
+        vec![format!("{src:#}a/"), format!("{src:#}top.txt")]
+    );
+
+    let dest = folder(format!("{dir:#}dest/"));
+    dest.create_all().unwrap();
+    let copy = src.copy_tree_to(&dest).unwrap();
+    assert_eq!(copy.as_str(), format!("{dest:#}src/"));
+    assert_eq!(
+        file(format!("{copy:#}a/b/deep.txt"))
+            .read_to_string()
+            .unwrap(),
+        "deep"
+    );
+    assert_eq!(
+        file(format!("{copy:#}top.txt")).read_to_string().unwrap(),
+        "top"
+    );
+}
+
+#[test]
+fn copy_tree_into_itself() {
+    let tmp = TempFolder::new().unwrap();
+    let src = folder(format!("{:#}src/", tmp.path()));
+    let inner = folder(format!("{src:#}inner/"));
+    inner.create_all().unwrap();
+
+    let err = src.copy_tree_to(&inner).unwrap_err().to_string();
+    assert!(err.starts_with("cannot copy folder "), "{err}");
+    assert!(src.copy_tree_to(&src).is_err());
+    assert!(!folder(format!("{inner:#}src/")).exists());
+}
+
+#[test]
+fn copy_onto_itself() {
+    let tmp = TempFolder::new().unwrap();
+    let src = folder(format!("{:#}src/", tmp.path()));
+    src.create_all().unwrap();
+    let text = file(format!("{src:#}f.txt"));
+    text.write("text").unwrap();
+
+    let err = text.copy_to(&src).unwrap_err().to_string();
+    assert!(err.starts_with("cannot copy file "), "{err}");
+    assert_eq!(text.read_to_string().unwrap(), "text");
+
+    let err = src.copy_tree_to(tmp.path()).unwrap_err().to_string();
+    assert!(err.starts_with("cannot copy folder "), "{err}");
+    assert_eq!(text.read_to_string().unwrap(), "text");
+
+    #[cfg(unix)]
+    {
+        let link = folder(format!("{:#}link/", tmp.path()));
+        std::os::unix::fs::symlink(&src, link.as_str().trim_end_matches('/')).unwrap();
+        assert!(text.copy_to(&link).is_err());
+        assert_eq!(text.read_to_string().unwrap(), "text");
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn copy_tree_keeps_links() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+    let src = folder(format!("{dir:#}src/"));
+    src.create_all().unwrap();
+    file(format!("{src:#}file.txt")).write("text").unwrap();
+    std::os::unix::fs::symlink(&src, format!("{src:#}loop")).unwrap();
+    std::os::unix::fs::symlink("file.txt", format!("{src:#}link.txt")).unwrap();
+
+    let dest = folder(format!("{dir:#}dest/"));
+    dest.create_all().unwrap();
+    let copy = src.copy_tree_to(&dest).unwrap();
+
+    let link = std::path::PathBuf::from(format!("{copy:#}loop"));
+    assert!(link.symlink_metadata().unwrap().is_symlink());
+    assert_eq!(std::fs::read_link(&link).unwrap(), src.as_path());
+    assert_eq!(
+        file(format!("{copy:#}link.txt")).read_to_string().unwrap(),
+        "text"
+    );
+    assert!(!std::path::Path::new(&format!("{copy:#}loop/loop"))
+        .symlink_metadata()
+        .is_ok_and(|m| m.is_dir()));
+}
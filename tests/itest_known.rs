@@ -0,0 +1,42 @@
+use x_path::{tree, AbsoluteFolderPath, KnownFolders, TempFolder};
+
+#[test]
+fn known_folders() {
+    assert!(AbsoluteFolderPath::config_dir().is_ok());
+    assert!(AbsoluteFolderPath::cache_dir().is_ok());
+    assert!(AbsoluteFolderPath::data_dir().is_ok());
+    assert!(AbsoluteFolderPath::state_dir().is_ok());
+}
+
+#[cfg(unix)]
+#[test]
+fn xdg_env() {
+    let tmp = TempFolder::new().unwrap();
+    let home = AbsoluteFolderPath::try_from("~").unwrap();
+
+    tree! {
+        "user-dirs.dirs" => "XDG_DOWNLOAD_DIR=\"$HOME/Dl\"\nXDG_DOCUMENTS_DIR=\"$HOME/\"\n",
+    }
+    .create_in(&tmp)
+    .unwrap();
+
+    let known = KnownFolders::from_env(|key| match key {
+        "XDG_CONFIG_HOME" => Some(tmp.path().as_str().to_string()),
+        "XDG_CACHE_HOME" => Some("relative/is/ignored".to_string()),
+        _ => None,
+    });
+
+    assert_eq!(&known.config_dir().unwrap(), tmp.path());
+    assert_eq!(
+        known.cache_dir().unwrap().as_str(),
+        format!("{}.cache/", home.as_str())
+    );
+    assert_eq!(known.runtime_dir().unwrap(), None);
+
+    let dirs = known.user_dirs().unwrap();
+    assert_eq!(dirs.documents, None);
+    assert_eq!(
+        dirs.downloads.unwrap().as_str(),
+        format!("{}Dl/", home.as_str())
+    );
+}
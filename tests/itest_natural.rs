@@ -0,0 +1,42 @@
+use x_path::{natural_cmp, natural_cmp_folders_first, tree, FileLike, PathLike, TempFolder};
+
+#[test]
+fn sort_listing() {
+    let tmp = TempFolder::new().unwrap();
+    tree! {
+        "file10.png" => "",
+        "file2.png" => "",
+        "file1.png" => "",
+        "assets/" => {},
+        "zz/" => {},
+    }
+    .create_in(&tmp)
+    .unwrap();
+
+    let names = |paths: &[x_path::AbsolutePath]| {
+        paths
+            .iter()
+            .map(|p| p.segments().next_back().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let mut paths = tmp.read_dir().unwrap();
+    paths.sort_by(natural_cmp);
+    assert_eq!(
+        names(&paths),
+        ["assets", "file1.png", "file2.png", "file10.png", "zz"]
+    );
+
+    paths.sort_by(natural_cmp_folders_first);
+    assert_eq!(
+        names(&paths),
+        ["assets", "zz", "file1.png", "file2.png", "file10.png"]
+    );
+
+    let mut files = paths
+        .into_iter()
+        .filter_map(|p| p.to_concrete().right())
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| natural_cmp(b, a));
+    assert_eq!(files[0].file_name(), "file10.png");
+}
@@ -0,0 +1,73 @@
+use std::fs;
+
+use serde::Deserialize;
+use x_path::{AbsoluteFilePath, AbsoluteFolderPath, TempFolder, TryOutput};
+
+#[derive(Deserialize, Debug)]
+struct Outputs {
+    #[serde(with = "x_path::absolute_file_path::creatable")]
+    report: AbsoluteFilePath,
+    #[serde(with = "x_path::absolute_folder_path::fresh")]
+    fresh: AbsoluteFolderPath,
+    #[serde(with = "x_path::absolute_folder_path::ensure_dir")]
+    scratch: AbsoluteFolderPath,
+}
+
+fn outputs(report: &str, fresh: &str, scratch: &str) -> Result<Outputs, String> {
+    let json = serde_json::json!({ "report": report, "fresh": fresh, "scratch": scratch });
+    serde_json::from_value::<Outputs>(json).map_err(|e| e.to_string())
+}
+
+#[test]
+fn output_modes() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+
+    let out = outputs(
+        &format!("{dir}report.txt"),
+        &format!("{dir}fresh/"),
+        &format!("{dir}scratch/sub/"),
+    )
+    .unwrap();
+    assert!(!out.report.exists());
+    assert!(!out.fresh.exists());
+    assert!(out.scratch.exists());
+
+    // the folder for a creatable file must exist
+    let err = outputs(
+        &format!("{dir}missing/report.txt"),
+        &format!("{dir}fresh/"),
+        &format!("{dir}scratch/"),
+    )
+    .unwrap_err();
+    assert!(err.starts_with("folder doesn't exist: "), "{err}");
+
+    // a fresh path must not exist
+    let err = outputs(
+        &format!("{dir}report.txt"),
+        &format!("{dir}scratch/"),
+        &format!("{dir}scratch/"),
+    )
+    .unwrap_err();
+    assert!(err.starts_with("path already exists: "), "{err}");
+}
+
+#[test]
+fn output_traits() {
+    let tmp = TempFolder::new().unwrap();
+    let dir = tmp.path();
+
+    let file = AbsoluteFilePath::try_ensure_dir(format!("{dir}a/b/file.txt")).unwrap();
+    assert!(!file.exists());
+    assert!(file.dropping_file().exists());
+
+    fs::write(&file, "hi").unwrap();
+    assert!(AbsoluteFilePath::try_creatable(file.as_str()).is_ok());
+    assert!(AbsoluteFilePath::try_fresh(file.as_str()).is_err());
+
+    // the path is taken by a file
+    let err = AbsoluteFolderPath::try_creatable(format!("{}/", file.as_str())).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("path is a file, not a folder: "));
+}
@@ -0,0 +1,60 @@
+use x_path::{redact, AbsoluteFolderPath, Redactions, TempFolder};
+
+#[test]
+fn standard_roots() {
+    let tmp = TempFolder::new().unwrap();
+    let file = format!("{:#}data.json", tmp.path());
+    let redacted = redact(&format!("wrote {file}")).unwrap();
+    assert!(redacted.starts_with("wrote [TMP]/"), "{redacted}");
+    assert!(redacted.ends_with("data.json"), "{redacted}");
+
+    let home = AbsoluteFolderPath::try_from("~").unwrap();
+    let redacted = redact(&format!("{home:#}")).unwrap();
+    assert!(redacted.starts_with("[HOME]"), "{redacted}");
+}
+
+#[test]
+fn custom_root_wins_over_standard() {
+    let tmp = TempFolder::new().unwrap();
+    let redactions = Redactions::standard()
+        .unwrap()
+        .with_root(tmp.path(), "FIXTURE")
+        .unwrap();
+    let text = format!("{:#}a.txt", tmp.path());
+    assert_eq!(redactions.redact(&text), "[FIXTURE]/a.txt");
+}
+
+#[test]
+fn invalid_name() {
+    let tmp = TempFolder::new().unwrap();
+    let err = Redactions::new().with_root(tmp.path(), "[X]").unwrap_err();
+    assert!(err.to_string().starts_with("invalid redaction name '[X]'"));
+}
+
+#[cfg(all(feature = "redact-json", not(windows)))]
+#[test]
+fn redact_value() {
+    #[derive(serde::Serialize)]
+    struct Build {
+        out: AbsoluteFolderPath,
+        inputs: Vec<String>,
+        sizes: std::collections::BTreeMap<String, u64>,
+    }
+
+    let tmp = TempFolder::new().unwrap();
+    let redactions = Redactions::new().with_root(tmp.path(), "OUT").unwrap();
+    let build = Build {
+        out: tmp.path().clone(),
+        inputs: vec![format!("{:#}src/a.rs", tmp.path()), "b.rs".into()],
+        sizes: [(format!("{:#}a.o", tmp.path()), 3)].into(),
+    };
+    let value = redactions.redact_value(&build).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "out": "[OUT]/",
+            "inputs": ["[OUT]/src/a.rs", "b.rs"],
+            "sizes": { "[OUT]/a.o": 3 },
+        })
+    );
+}
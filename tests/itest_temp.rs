@@ -0,0 +1,63 @@
+use x_path::{TempFile, TempFolder};
+
+#[test]
+fn temp_folder() {
+    let tmp = TempFolder::new().unwrap();
+    let path = tmp.path().clone();
+    assert!(path.exists());
+    assert!(path.as_str().ends_with(std::path::MAIN_SEPARATOR));
+
+    let nested = TempFolder::new_in(&tmp).unwrap();
+    assert!(nested.as_str().starts_with(path.as_str()));
+
+    drop(nested);
+    drop(tmp);
+    assert!(!path.exists());
+}
+
+#[test]
+fn temp_folder_keep() {
+    let tmp = TempFolder::new().unwrap();
+    let path = tmp.keep();
+    assert!(path.exists());
+    path.remove_all().unwrap();
+}
+
+#[test]
+fn temp_file() {
+    let tmp = TempFolder::new().unwrap();
+
+    let file = TempFile::with_extension("json").unwrap();
+    assert!(file.exists());
+    assert_eq!(file.extension(), Some("json"));
+    assert_eq!(file.read_to_string().unwrap(), "");
+
+    let inside = TempFile::new_in(&tmp, "").unwrap();
+    assert_eq!(inside.extension(), None);
+    assert_eq!(inside.dropping_file().as_str(), tmp.as_str());
+
+    let path = file.path().clone();
+    drop(file);
+    assert!(!path.exists());
+
+    let kept = TempFile::new_in(&tmp, "txt").unwrap().keep();
+    assert!(kept.exists());
+}
+
+#[test]
+fn temp_pairs() {
+    let (guard, dir) = TempFolder::new_pair().unwrap();
+    assert_eq!(guard.path(), &dir);
+    let (file_guard, file) = TempFile::new_pair_in(&dir, "txt").unwrap();
+    assert_eq!(file.extension(), Some("txt"));
+    assert!(file.exists());
+
+    drop(file_guard);
+    assert!(!file.exists());
+    let (_nested, nested) = TempFolder::new_pair_in(&dir).unwrap();
+    drop(guard);
+    assert!(!dir.exists() && !nested.exists());
+
+    let (_guard, file) = TempFile::new_pair("json").unwrap();
+    assert!(file.exists());
+}
@@ -0,0 +1,72 @@
+use x_path::{assert_tree_eq, tree, TempFolder, Tree, TreeDiff};
+
+#[test]
+fn create_and_read() {
+    let tmp = TempFolder::new().unwrap();
+    let spec = tree! {
+        "src/" => {
+            "main.rs" => "fn main() {}",
+            "empty/" => {},
+        },
+        "README.md" => "# readme",
+    };
+    spec.create_in(&tmp).unwrap();
+
+    assert_eq!(Tree::read(&tmp).unwrap(), spec);
+    assert_tree_eq(&tmp, &spec);
+}
+
+#[test]
+fn diff() {
+    let expected = tree! {
+        "src/" => { "lib.rs" => "", "main.rs" => "fn main() {}" },
+        "a" => "",
+    };
+    let actual = tree! {
+        "src/" => { "main.rs" => "fn main() { todo!() }", "extra.rs" => "" },
+        "a/" => {},
+    };
+    let diffs = expected.diff(&actual);
+    assert_eq!(
+        diffs,
+        vec![
+            TreeDiff::Missing("a".into()),
+            TreeDiff::Unexpected("a/".into()),
+            TreeDiff::Missing("src/lib.rs".into()),
+            TreeDiff::Changed {
+                path: "src/main.rs".into(),
+                expected: "fn main() {}".into(),
+                actual: "fn main() { todo!() }".into(),
+            },
+            TreeDiff::Unexpected("src/extra.rs".into()),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "unexpected: b.txt")]
+fn assert_reports_differences() {
+    let tmp = TempFolder::new().unwrap();
+    tree! { "a.txt" => "a", "b.txt" => "b" }
+        .create_in(&tmp)
+        .unwrap();
+    assert_tree_eq(&tmp, &tree! { "a.txt" => "a" });
+}
+
+#[test]
+#[should_panic(expected = "invalid tree entry")]
+fn nested_name_is_rejected() {
+    let _ = tree! { "src/main.rs" => "" };
+}
+
+#[test]
+#[should_panic(expected = "invalid tree entry '.': '.' is not allowed")]
+fn dot_name_is_rejected() {
+    let _ = tree! { "." => "" };
+}
+
+#[test]
+#[should_panic(expected = "invalid tree entry '../': '..' is not allowed")]
+fn dot_dot_name_is_rejected() {
+    let _ = tree! { "../" => {} };
+}